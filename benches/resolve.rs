@@ -0,0 +1,94 @@
+//! Measures how long it takes to update large amounts of text.
+//!
+//! Run with `cargo bench --bench resolve`.
+
+use bevy::{asset::uuid::Uuid, prelude::*};
+use bevy_reactive_font::prelude::*;
+use std::time::{Duration, Instant};
+
+const TEXT_COUNTS: [usize; 3] = [1_000, 10_000, 50_000];
+const RUNS: usize = 10;
+
+fn font(id: u128) -> Handle<Font> {
+    Handle::Uuid(Uuid::from_u128(id), Default::default())
+}
+
+/// An app with a single collection and `count` texts using it.
+fn app(count: usize) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ReactiveFontPlugin::default(),
+    ));
+
+    let collection = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(font(1)), BoldFont(font(2))))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+
+    let root = app.world_mut().spawn(Node::default()).id();
+    app.world_mut()
+        .spawn_batch((0..count).map(move |_| (Text::new("text"), ReactiveFont, ChildOf(root))));
+
+    (app, collection)
+}
+
+/// Runs `setup` then times a single update, returning the median of several runs.
+fn measure(count: usize, mut setup: impl FnMut(&mut App, Entity)) -> Duration {
+    let mut times = (0..RUNS)
+        .map(|_| {
+            let (mut app, collection) = app(count);
+            app.update();
+
+            setup(&mut app, collection);
+
+            let start = Instant::now();
+            app.update();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    times.sort();
+    times[RUNS / 2]
+}
+
+fn main() {
+    TEXT_COUNTS.into_iter().for_each(|count| {
+        let spawn = {
+            let mut times = (0..RUNS)
+                .map(|_| {
+                    let (mut app, _) = app(count);
+                    let start = Instant::now();
+                    app.update();
+                    start.elapsed()
+                })
+                .collect::<Vec<_>>();
+            times.sort();
+            times[RUNS / 2]
+        };
+
+        let size = measure(count, |app, collection| {
+            app.world_mut()
+                .entity_mut(collection)
+                .insert(DefaultFontSize(32.));
+        });
+
+        let color = measure(count, |app, collection| {
+            app.world_mut()
+                .entity_mut(collection)
+                .insert(DefaultFontColor::new(Color::BLACK));
+        });
+
+        let bold = measure(count, |app, collection| {
+            app.world_mut()
+                .entity_mut(collection)
+                .insert(BoldFont(font(3)));
+        });
+
+        println!(
+            "{count:>6} texts: spawn {spawn:>10.2?}, default size {size:>10.2?}, default color {color:>10.2?}, font handle {bold:>10.2?}"
+        );
+    });
+}
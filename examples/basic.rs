@@ -16,61 +16,35 @@ const BACKGROUND: Color = Color::srgb_u8(15, 42, 63);
 #[derive(Component)]
 pub struct UiRoot;
 
-#[derive(Component)]
-pub struct LiberationMono;
-
-#[derive(Component)]
-pub struct OpenDyslexic;
-
-#[derive(Resource, Default)]
-pub enum CurrentFontColor {
-    Green,
-    Lime,
-    Blue,
-    DeepBlue,
-    #[default]
-    Purple,
-    Pink,
+const THEMES: [(&str, Color); 6] = [
+    ("green", GREEN),
+    ("lime", LIME),
+    ("blue", BLUE),
+    ("deep_blue", DEEP_BLUE),
+    ("purple", PURPLE),
+    ("pink", PINK),
+];
+
+fn font_theme() -> FontTheme {
+    THEMES
+        .into_iter()
+        .fold(FontTheme::new("purple"), |theme, (name, color)| {
+            theme.with_theme(name, ThemePalette::new().with_color("primary", color))
+        })
 }
-impl CurrentFontColor {
-    pub fn cycle(&mut self) -> &mut Self {
-        *self = match self {
-            Self::Green => Self::Lime,
-            Self::Lime => Self::Blue,
-            Self::Blue => Self::DeepBlue,
-            Self::DeepBlue => Self::Purple,
-            Self::Purple => Self::Pink,
-            Self::Pink => Self::Green,
-        };
-        self
-    }
-    pub fn cycle_back(&mut self) -> &mut Self {
-        *self = match self {
-            Self::Green => Self::Lime,
-            Self::Lime => Self::Blue,
-            Self::Blue => Self::DeepBlue,
-            Self::DeepBlue => Self::Purple,
-            Self::Purple => Self::Pink,
-            Self::Pink => Self::Green,
-        };
-        self
-    }
-    pub fn into_color(&self) -> Color {
-        match self {
-            Self::Green => GREEN,
-            Self::Lime => LIME,
-            Self::Blue => BLUE,
-            Self::DeepBlue => DEEP_BLUE,
-            Self::Purple => PURPLE,
-            Self::Pink => PINK,
-        }
-    }
+
+fn cycle_theme(theme: &mut FontTheme, step: usize) {
+    let current = THEMES
+        .iter()
+        .position(|(name, _)| *name == theme.active())
+        .unwrap_or_default();
+    theme.set_active(THEMES[(current + step) % THEMES.len()].0);
 }
 
 pub fn main() -> AppExit {
     App::new()
-        .add_plugins((DefaultPlugins, ReactiveFontPlugin))
-        .init_resource::<CurrentFontColor>()
+        .add_plugins((DefaultPlugins, ReactiveFontPlugin::default()))
+        .insert_resource(font_theme())
         .insert_resource(ClearColor(BACKGROUND))
         .add_systems(Startup, (spawn_camera, spawn_fonts, spawn_text).chain())
         .add_systems(
@@ -78,9 +52,8 @@ pub fn main() -> AppExit {
             (
                 increase_default_font_size.run_if(input_pressed(KeyCode::ArrowUp)),
                 decrease_default_font_size.run_if(input_pressed(KeyCode::ArrowDown)),
-                cycle_default_color_left.run_if(input_just_pressed(KeyCode::ArrowLeft)),
-                cycle_default_color_right.run_if(input_just_pressed(KeyCode::ArrowRight)),
-                update_default_color.run_if(resource_changed::<CurrentFontColor>),
+                cycle_theme_left.run_if(input_just_pressed(KeyCode::ArrowLeft)),
+                cycle_theme_right.run_if(input_just_pressed(KeyCode::ArrowRight)),
             ),
         )
         .run()
@@ -91,21 +64,10 @@ fn spawn_camera(mut commands: Commands) {
 }
 
 fn spawn_fonts(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let regular_font = asset_server.load("fonts/liberation_mono/regular.ttf");
-    let italic_font = asset_server.load("fonts/liberation_mono/italic.ttf");
-    let bold_font = asset_server.load("fonts/liberation_mono/bold.ttf");
-    let bold_italic_font = asset_server.load("fonts/liberation_mono/bold_italic.ttf");
-
+    // Collections can also be loaded from a file
     let default_font = commands
-        .spawn((
-            FontCollection,
-            LiberationMono,
-            RegularFont(regular_font),
-            ItalicFont(italic_font),
-            BoldFont(bold_font),
-            BoldItalicFont(bold_italic_font),
-            DefaultFontSize(20.),
-            DefaultFontColor::new(PURPLE),
+        .spawn(FontCollectionHandle::new(
+            asset_server.load("fonts/liberation_mono.fontcollection.ron"),
         ))
         .id();
 
@@ -118,7 +80,7 @@ fn spawn_fonts(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     commands.spawn((
         FontCollection,
-        OpenDyslexic,
+        FontName::new("open_dyslexic"),
         RegularFont(regular_font),
         ItalicFont(italic_font),
         BoldFont(bold_font),
@@ -128,34 +90,29 @@ fn spawn_fonts(mut commands: Commands, asset_server: Res<AssetServer>) {
     ));
 }
 
-fn spawn_text(
-    mut commands: Commands,
-    camera: Single<Entity, With<Camera2d>>,
-    open_dyslexic: Single<Entity, With<OpenDyslexic>>,
-) {
+fn spawn_text(mut commands: Commands, camera: Single<Entity, With<Camera2d>>) {
     let fonted_font = commands
         .spawn((
             ReactiveFont,
             Text::new("Wow this took way too long."),
             FontColor::new(css::REBECCA_PURPLE),
+            UseFontNamed::new("open_dyslexic"),
         ))
         .id();
 
-    commands
-        .entity(*open_dyslexic)
-        .add_one_related::<UsingFont>(fonted_font);
-
     commands
         .spawn((
             UiRoot,
             UiTargetCamera(*camera),
+            // Colors can come from the active theme
+            FontColor::theme("primary"),
             Node {
                 row_gap: Val::Percent(2.),
                 flex_direction: FlexDirection::Column,
                 ..Default::default()
             },
             children![
-                (Text::new("Hello there"), ReactiveFont),
+                (Text::new("Hello there"), TextRole::Heading1, ReactiveFont),
                 (Text::new("I did a cool thing!"), Bold, Italic, ReactiveFont),
                 (
                     Text::new("And came up with a way of storing fonts."),
@@ -167,18 +124,30 @@ fn spawn_text(
                     ReactiveFont,
                 ),
                 (
-                    Text::new("You can even set the default font color and size with DefaultFontColor and DefaultFontSize"),
+                    Text::new("You can even set the default font color and size with DefaultFontColor and DefaultFontSize, or use a color from the FontTheme"),
                     ReactiveFont
                 ),
                 (
                     Text::new("And then override them with FontColor and FontSize on the actual text"),
-                    FontSize(25.),
+                    FontSize::Px(25.),
                     FontColor::new(Color::srgb_u8(94, 145, 136)),
                     ReactiveFont,
                 ),
                 (
                     Text::new("A default font can be set, and you can use relationships to specificy what text uses what font"),
                     ReactiveFont,
+                ),
+                (
+                    Text::new("Spans use the same font as the text they're in, "),
+                    ReactiveFont,
+                    children![
+                        (TextSpan::new("so you can make one word bold "), Bold, ReactiveFont),
+                        (TextSpan::new("without touching the rest."), ReactiveFont),
+                    ],
+                ),
+                (
+                    Text::default(),
+                    Markup::new("Or write them with **markup**, *which* is [color=#85d1d1]much quicker[/color]"),
                 )
             ],
         ))
@@ -191,16 +160,17 @@ fn spawn_text(
             flex_direction: FlexDirection::Column,
             ..Default::default()
         },
+        // Sizes can follow the size of the window too
+        FontSize::VMin(2.),
+        FontSizeClamp::new(12., 20.),
         children![
             (
                 Text::new("Press the up and down arrows to increase the default text size"),
-                FontSize::new(15.),
                 FontColor::new(PURPLE),
                 ReactiveFont
             ),
             (
-                Text::new("And the right and left arrows to change the default font color"),
-                FontSize::new(15.),
+                Text::new("And the right and left arrows to change the theme"),
                 FontColor::new(PURPLE),
                 ReactiveFont
             )
@@ -220,19 +190,10 @@ fn decrease_default_font_size(mut font_size: Query<&mut DefaultFontSize>) {
     })
 }
 
-fn cycle_default_color_right(mut current_font_color: ResMut<CurrentFontColor>) {
-    current_font_color.cycle();
-}
-
-fn cycle_default_color_left(mut current_font_color: ResMut<CurrentFontColor>) {
-    current_font_color.cycle_back();
+fn cycle_theme_right(mut theme: ResMut<FontTheme>) {
+    cycle_theme(&mut theme, 1);
 }
 
-fn update_default_color(
-    mut default_font_color: Query<&mut DefaultFontColor>,
-    current_font_color: Res<CurrentFontColor>,
-) {
-    default_font_color.iter_mut().for_each(|mut color| {
-        color.0 = current_font_color.into_color();
-    });
+fn cycle_theme_left(mut theme: ResMut<FontTheme>) {
+    cycle_theme(&mut theme, THEMES.len() - 1);
 }
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+/// Settings that apply to every [`ReactiveFont`] at once, without touching any of the text.
+///
+/// Changing these will update all the text.
+///
+/// [`ReactiveFont`]: crate::font::ReactiveFont
+#[derive(Resource, Reflect, PartialEq, Clone, Default, Debug)]
+pub struct AccessibilitySettings {
+    /// A [`FontCollection`] all text will use instead of its own, such as one with a font made for
+    /// readers with dyslexia.
+    ///
+    /// [`FontCollection`]: crate::font::FontCollection
+    pub dyslexic_font: Option<Entity>,
+    /// A color all text will use instead of its own.
+    pub high_contrast: Option<Color>,
+    /// The smallest text can be in pixels, after it has been scaled.
+    pub min_font_size: Option<f32>,
+    /// Stops any text from being italic.
+    pub disable_italics: bool,
+}
+impl AccessibilitySettings {
+    /// Creates new [`AccessibilitySettings`] that don't change anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the collection all text will use
+    pub fn with_dyslexic_font(mut self, font: Entity) -> Self {
+        self.dyslexic_font = Some(font);
+        self
+    }
+    /// Sets the color all text will use
+    pub fn with_high_contrast(mut self, color: impl Into<Color>) -> Self {
+        self.high_contrast = Some(color.into());
+        self
+    }
+    /// Sets the smallest text can be in pixels
+    pub fn with_min_font_size(mut self, size: f32) -> Self {
+        self.min_font_size = Some(size);
+        self
+    }
+    /// Stops any text from being italic
+    pub fn with_italics_disabled(mut self) -> Self {
+        self.disable_italics = true;
+        self
+    }
+}
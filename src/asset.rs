@@ -0,0 +1,263 @@
+use crate::prelude::*;
+use bevy::{
+    asset::{AssetLoader, LoadContext, UntypedAssetId, VisitAssetDependencies, io::Reader},
+    platform::collections::HashSet,
+    prelude::*,
+};
+use serde::Deserialize;
+
+/// A [`FontCollection`] loaded from a `.fontcollection.ron` file.
+///
+/// ```ron
+/// (
+///     name: Some("liberation_mono"),
+///     regular: "liberation_mono/regular.ttf",
+///     italic: Some("liberation_mono/italic.ttf"),
+///     bold: Some("liberation_mono/bold.ttf"),
+///     bold_italic: Some("liberation_mono/bold_italic.ttf"),
+///     faces: [(weight: 300, italic: false, path: "liberation_mono/light.ttf")],
+///     default_size: Some(20.0),
+///     default_color: Some("#bf85d1"),
+/// )
+/// ```
+///
+/// Font paths are relative to the file. Everything other than `regular` can be left out, and
+/// will be reset on the [`FontCollection`] if it's removed from the file. Names, sizes and colors
+/// the file never set are left as they are.
+#[derive(TypePath, Clone, Debug)]
+pub struct FontCollectionAsset {
+    pub name: Option<String>,
+    pub regular: RegularFont,
+    pub italic: ItalicFont,
+    pub bold: BoldFont,
+    pub bold_italic: BoldItalicFont,
+    pub faces: FontFaces,
+    pub default_size: Option<DefaultFontSize>,
+    pub default_color: Option<DefaultFontColor>,
+}
+impl Asset for FontCollectionAsset {}
+impl VisitAssetDependencies for FontCollectionAsset {
+    fn visit_dependencies(&self, visit: &mut impl FnMut(UntypedAssetId)) {
+        [
+            &self.regular.0,
+            &self.italic.0,
+            &self.bold.0,
+            &self.bold_italic.0,
+        ]
+        .into_iter()
+        .chain(self.faces.iter().map(|face| &face.font))
+        .for_each(|font| visit(font.id().untyped()));
+    }
+}
+
+/// Populates the [`FontCollection`] it's placed on from a [`FontCollectionAsset`], and keeps it up
+/// to date when the file changes.
+#[derive(Component, Reflect, Deref, DerefMut, PartialEq, Eq, Clone, Default, Debug)]
+#[require(FontCollection)]
+pub struct FontCollectionHandle(pub Handle<FontCollectionAsset>);
+impl From<Handle<FontCollectionAsset>> for FontCollectionHandle {
+    fn from(value: Handle<FontCollectionAsset>) -> Self {
+        Self::new(value)
+    }
+}
+impl FontCollectionHandle {
+    /// Creates a new [`FontCollectionHandle`]
+    pub fn new(value: Handle<FontCollectionAsset>) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal [`Handle`]
+    pub fn into_inner(&self) -> &Handle<FontCollectionAsset> {
+        &self.0
+    }
+}
+
+#[derive(Deserialize)]
+struct FontCollectionManifest {
+    #[serde(default)]
+    name: Option<String>,
+    regular: String,
+    #[serde(default)]
+    italic: Option<String>,
+    #[serde(default)]
+    bold: Option<String>,
+    #[serde(default)]
+    bold_italic: Option<String>,
+    #[serde(default)]
+    faces: Vec<FontFaceManifest>,
+    #[serde(default)]
+    default_size: Option<f32>,
+    #[serde(default)]
+    default_color: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct FontFaceManifest {
+    weight: u16,
+    #[serde(default)]
+    italic: bool,
+    path: String,
+}
+
+/// Loads [`FontCollectionAsset`]'s from `.fontcollection.ron` files.
+#[derive(Default)]
+pub struct FontCollectionLoader;
+
+impl AssetLoader for FontCollectionLoader {
+    type Asset = FontCollectionAsset;
+    type Settings = ();
+    type Error = FontCollectionLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let manifest = ron::de::from_bytes::<FontCollectionManifest>(&bytes)?;
+
+        let regular = RegularFont(load_font(load_context, &manifest.regular)?);
+        let italic = ItalicFont(load_optional_font(load_context, manifest.italic)?);
+        let bold = BoldFont(load_optional_font(load_context, manifest.bold)?);
+        let bold_italic = BoldItalicFont(load_optional_font(load_context, manifest.bold_italic)?);
+
+        let faces = manifest
+            .faces
+            .into_iter()
+            .map(|face| {
+                Ok(FontFace {
+                    weight: FontWeight(face.weight),
+                    italic: face.italic,
+                    font: load_font(load_context, &face.path)?,
+                })
+            })
+            .collect::<Result<Vec<_>, FontCollectionLoaderError>>()?;
+
+        let default_color = manifest
+            .default_color
+            .map(|color| {
+                Srgba::hex(&color)
+                    .map(DefaultFontColor::new)
+                    .map_err(|_| FontCollectionLoaderError::InvalidColor(color))
+            })
+            .transpose()?;
+
+        Ok(FontCollectionAsset {
+            name: manifest.name,
+            regular,
+            italic,
+            bold,
+            bold_italic,
+            faces: FontFaces(faces),
+            default_size: manifest.default_size.map(DefaultFontSize),
+            default_color,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["fontcollection.ron"]
+    }
+}
+
+/// Loads a font relative to the file being loaded.
+fn load_font(
+    load_context: &mut LoadContext,
+    path: &str,
+) -> Result<Handle<Font>, FontCollectionLoaderError> {
+    let path = load_context.asset_path().resolve_embed(path)?;
+    Ok(load_context.load(path))
+}
+
+/// Missing fonts are left as [`Handle::default`], the same as a [`FontCollection`] that doesn't
+/// set them.
+fn load_optional_font(
+    load_context: &mut LoadContext,
+    path: Option<String>,
+) -> Result<Handle<Font>, FontCollectionLoaderError> {
+    path.map(|path| load_font(load_context, &path))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// The optional fields the last version of the file set, so they can be reset if they're removed
+/// from it without touching ones set by hand.
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub(crate) struct ManifestFields {
+    name: bool,
+    default_size: bool,
+    default_color: bool,
+}
+
+pub(crate) fn font_collection_asset_changed(
+    mut commands: Commands,
+    mut asset_events: MessageReader<AssetEvent<FontCollectionAsset>>,
+    assets: Res<Assets<FontCollectionAsset>>,
+    collections: Query<(Entity, Ref<FontCollectionHandle>, Option<&ManifestFields>)>,
+) {
+    let changed = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    collections
+        .iter()
+        .filter(|(_, handle, _)| handle.is_changed() || changed.contains(&handle.id()))
+        .filter_map(|(entity, handle, fields)| {
+            Some((
+                entity,
+                assets.get(&handle.0)?,
+                fields.copied().unwrap_or_default(),
+            ))
+        })
+        .for_each(|(entity, asset, fields)| {
+            let mut collection = commands.entity(entity);
+
+            collection.insert((
+                asset.regular.clone(),
+                asset.italic.clone(),
+                asset.bold.clone(),
+                asset.bold_italic.clone(),
+                asset.faces.clone(),
+            ));
+
+            // Anything the file no longer sets goes back to how it would be without it. The
+            // defaults are required by `FontCollection`, so they're reset rather than removed.
+            match &asset.name {
+                Some(name) => {
+                    collection.insert(FontName::new(name.clone()));
+                }
+                None if fields.name => {
+                    collection.remove::<FontName>();
+                }
+                None => {}
+            }
+            match &asset.default_size {
+                Some(size) => {
+                    collection.insert(size.clone());
+                }
+                None if fields.default_size => {
+                    collection.insert(DefaultFontSize::default());
+                }
+                None => {}
+            }
+            match &asset.default_color {
+                Some(color) => {
+                    collection.insert(color.clone());
+                }
+                None if fields.default_color => {
+                    collection.insert(DefaultFontColor::default());
+                }
+                None => {}
+            }
+
+            collection.insert(ManifestFields {
+                name: asset.name.is_some(),
+                default_size: asset.default_size.is_some(),
+                default_color: asset.default_color.is_some(),
+            });
+        });
+}
@@ -0,0 +1,128 @@
+use crate::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// Styles a peice of text using the classes of the same name in the [`TextStyleSheet`].
+///
+/// When there is more than one class they are applied in order, so later classes override earlier
+/// ones. Anything set directly on the text, such as [`FontSize`] or [`Bold`], takes priority over
+/// its classes, and classes take priority over its [`TextRole`].
+///
+/// When placed on a container every [`ReactiveFont`] below it will inherit the style.
+#[derive(Component, Reflect, Deref, DerefMut, PartialEq, Eq, Clone, Default, Debug)]
+pub struct TextClass(pub Vec<String>);
+impl<S: Into<String>> From<S> for TextClass {
+    fn from(value: S) -> Self {
+        Self::new(value)
+    }
+}
+impl TextClass {
+    /// Creates a new [`TextClass`] with a single class
+    pub fn new(class: impl Into<String>) -> Self {
+        Self(vec![class.into()])
+    }
+    /// Adds another class, which will override the ones before it
+    pub fn with(mut self, class: impl Into<String>) -> Self {
+        self.0.push(class.into());
+        self
+    }
+    /// Retrives the internal classes
+    pub fn into_inner(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// How text with a [`TextClass`] should look. Anything left as [`None`] is inherited as normal.
+#[derive(Reflect, PartialEq, Clone, Default, Debug)]
+pub struct ClassStyle {
+    pub size: Option<FontSize>,
+    pub color: Option<FontColor>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    /// The name of the [`FontCollection`] to use, as it is registered in the [`FontRegistry`]
+    pub font: Option<String>,
+    pub line_height: Option<LineHeight>,
+}
+impl ClassStyle {
+    /// Creates a new [`ClassStyle`] that doesn't change anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the size
+    pub fn with_size(mut self, size: impl Into<FontSize>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+    /// Sets the color
+    pub fn with_color(mut self, color: impl Into<FontColor>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+    /// Sets whether the text is bold
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = Some(bold);
+        self
+    }
+    /// Sets whether the text is italic
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+    /// Sets the [`FontCollection`] to use by name
+    pub fn with_font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+    /// Sets the line height
+    pub fn with_line_height(mut self, line_height: LineHeight) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+    /// Overrides anything this style sets with what the other one sets
+    pub fn merge(&mut self, other: &ClassStyle) {
+        self.size = other.size.or(self.size);
+        self.color = other.color.clone().or(self.color.take());
+        self.bold = other.bold.or(self.bold);
+        self.italic = other.italic.or(self.italic);
+        self.font = other.font.clone().or(self.font.take());
+        self.line_height = other.line_height.or(self.line_height);
+    }
+}
+
+/// All the styles that can be used by a [`TextClass`].
+///
+/// Changing this will restyle any text using the classes that were changed.
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+pub struct TextStyleSheet(HashMap<String, ClassStyle>);
+impl TextStyleSheet {
+    /// Adds a class
+    pub fn with_class(mut self, class: impl Into<String>, style: ClassStyle) -> Self {
+        self.0.insert(class.into(), style);
+        self
+    }
+    /// The style of a class
+    pub fn get(&self, class: &str) -> Option<&ClassStyle> {
+        self.0.get(class)
+    }
+    /// Sets the style of a class, returning the previous style
+    pub fn insert(&mut self, class: impl Into<String>, style: ClassStyle) -> Option<ClassStyle> {
+        self.0.insert(class.into(), style)
+    }
+    /// Removes a class, returning its style
+    pub fn remove(&mut self, class: &str) -> Option<ClassStyle> {
+        self.0.remove(class)
+    }
+    /// Iterates over each class and its style
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ClassStyle)> {
+        self.0.iter().map(|(class, style)| (class.as_str(), style))
+    }
+    /// Merges the styles of each class in order, classes that don't exist are skipped.
+    pub fn style(&self, classes: &TextClass) -> ClassStyle {
+        classes.iter().filter_map(|class| self.get(class)).fold(
+            ClassStyle::default(),
+            |mut style, class| {
+                style.merge(class);
+                style
+            },
+        )
+    }
+}
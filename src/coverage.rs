@@ -0,0 +1,221 @@
+use crate::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*, text::TextSpanAccess};
+use ttf_parser::Face;
+
+/// The characters in a peice of text that the face it's rendered with doesn't have glyphs for.
+///
+/// This is added to [`ReactiveFont`]'s when [`ReactiveFontPlugin::report_missing_glyphs`] is
+/// enabled, and removed again once the text can be rendered. Text waiting on a font to load isn't
+/// checked until it has.
+///
+/// Nothing is logged when this is added, observe `On<Add, MissingGlyphs>` to report it.
+#[derive(Component, Reflect, Deref, PartialEq, Eq, Clone, Debug)]
+pub struct MissingGlyphs(pub Vec<char>);
+
+/// Checks which characters a [`FontCollection`] can render, using the loaded [`Font`] data.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_reactive_font::prelude::*;
+/// fn check_translation(coverage: GlyphCoverage, default_font: Res<DefaultFont>) {
+///     if coverage.covers(default_font.0, "こんにちは") == Some(false) {
+///         warn!("The default font can't render Japanese");
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GlyphCoverage<'w, 's> {
+    regular_fonts: Query<'w, 's, &'static RegularFont, With<FontCollection>>,
+    fonts: Option<Res<'w, Assets<Font>>>,
+}
+impl GlyphCoverage<'_, '_> {
+    /// The regular face of a collection, if it has loaded.
+    pub(crate) fn face(&self, collection: Entity) -> Option<Face<'_>> {
+        let regular = self.regular_fonts.get(collection).ok()?;
+        self.font_face(&regular.0)
+    }
+
+    fn font_face(&self, font: &Handle<Font>) -> Option<Face<'_>> {
+        let font = self.fonts.as_ref()?.get(font)?;
+        Face::parse(&font.data, 0).ok()
+    }
+
+    /// Whether a collection has glyphs for all of the text, not counting its
+    /// [`FallbackCollections`].
+    ///
+    /// Glyphs are checked using the collection's [`RegularFont`], this is [`None`] until it has
+    /// loaded.
+    pub fn covers(&self, collection: Entity, text: &str) -> Option<bool> {
+        self.missing(collection, text)
+            .map(|missing| missing.is_empty())
+    }
+
+    /// Retrives the characters a collection doesn't have glyphs for, not counting its
+    /// [`FallbackCollections`].
+    ///
+    /// Glyphs are checked using the collection's [`RegularFont`], this is [`None`] until it has
+    /// loaded.
+    pub fn missing(&self, collection: Entity, text: &str) -> Option<Vec<char>> {
+        self.face(collection)
+            .map(|face| missing_glyphs(&face, text))
+    }
+
+    /// Retrives the characters a font doesn't have glyphs for, [`None`] until it has loaded.
+    pub fn missing_in_font(&self, font: &Handle<Font>, text: &str) -> Option<Vec<char>> {
+        self.font_face(font).map(|face| missing_glyphs(&face, text))
+    }
+}
+
+/// Each character without a glyph, once. Control characters such as new lines are never drawn so
+/// they aren't counted.
+fn missing_glyphs(face: &Face, text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|character| !character.is_control())
+        .filter(|character| face.glyph_index(*character).is_none())
+        .fold(Vec::new(), |mut missing, character| {
+            if !missing.contains(&character) {
+                missing.push(character);
+            }
+            missing
+        })
+}
+
+/// Checks the text against the face it's rendered with whenever either changes, or a font loads.
+#[allow(clippy::type_complexity)]
+pub(crate) fn report_missing_glyphs<T: TextSpanAccess>(
+    mut commands: Commands,
+    mut font_events: MessageReader<AssetEvent<Font>>,
+    coverage: GlyphCoverage,
+    texts: Query<(Entity, Ref<T>, Ref<TextFont>, Option<&MissingGlyphs>), With<ReactiveFont>>,
+) {
+    let font_loaded = font_events
+        .read()
+        .any(|event| matches!(event, AssetEvent::LoadedWithDependencies { .. }));
+
+    texts
+        .iter()
+        .filter(|(_, text, font, _)| font_loaded || text.is_changed() || font.is_changed())
+        .for_each(|(entity, text, font, report)| {
+            let Some(missing) = coverage.missing_in_font(&font.font, text.read_span()) else {
+                return;
+            };
+
+            match report {
+                Some(report) if report.0 == missing => {}
+                Some(_) if missing.is_empty() => {
+                    commands.entity(entity).try_remove::<MissingGlyphs>();
+                }
+                None if missing.is_empty() => {}
+                _ => {
+                    commands.entity(entity).try_insert(MissingGlyphs(missing));
+                }
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::ReactiveFontPlugin;
+    use bevy::ecs::system::RunSystemOnce;
+
+    /// OpenDyslexic has Latin glyphs, but no Greek or Japanese ones.
+    const FONT: &[u8] = include_bytes!("../assets/fonts/opendyslexic/regular.otf");
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ReactiveFontPlugin {
+                report_missing_glyphs: true,
+                ..Default::default()
+            },
+        ))
+        .init_asset::<Font>();
+        app
+    }
+
+    fn add_font(app: &mut App) -> Handle<Font> {
+        let font = Font::try_from_bytes(FONT.to_vec()).unwrap();
+        app.world_mut().resource_mut::<Assets<Font>>().add(font)
+    }
+
+    #[test]
+    fn missing_glyphs_are_only_counted_once() {
+        let face = Face::parse(FONT, 0).unwrap();
+        assert_eq!(missing_glyphs(&face, "Hello"), vec![]);
+        assert_eq!(missing_glyphs(&face, "αβα ok β"), vec!['α', 'β']);
+        assert_eq!(
+            missing_glyphs(&face, "日本語と日本"),
+            vec!['日', '本', '語', 'と']
+        );
+    }
+
+    #[test]
+    fn control_characters_are_not_missing() {
+        let face = Face::parse(FONT, 0).unwrap();
+        assert_eq!(missing_glyphs(&face, "one\ntwo\tthree\r\n"), vec![]);
+        assert_eq!(missing_glyphs(&face, "\u{7}ω\u{1b}"), vec!['ω']);
+    }
+
+    #[test]
+    fn collections_are_checked_with_their_regular_font() {
+        let mut app = app();
+        let font = add_font(&mut app);
+        let collection = app
+            .world_mut()
+            .spawn((FontCollection, RegularFont(font)))
+            .id();
+        let unloaded = app
+            .world_mut()
+            .spawn((FontCollection, RegularFont(Handle::default())))
+            .id();
+
+        let (missing, covers, unloaded) = app
+            .world_mut()
+            .run_system_once(move |coverage: GlyphCoverage| {
+                (
+                    coverage.missing(collection, "Hi Ωμέγα, ΩΩ\n"),
+                    (
+                        coverage.covers(collection, "Hi\n there"),
+                        coverage.covers(collection, "こんにちは"),
+                    ),
+                    (
+                        coverage.missing(unloaded, "Hi"),
+                        coverage.covers(unloaded, "Hi"),
+                    ),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(missing, Some(vec!['Ω', 'μ', 'έ', 'γ', 'α']));
+        assert_eq!(covers, (Some(true), Some(false)));
+        assert_eq!(unloaded, (None, None));
+    }
+
+    #[test]
+    fn missing_glyphs_are_added_and_removed() {
+        let mut app = app();
+        let font = add_font(&mut app);
+        let collection = app
+            .world_mut()
+            .spawn((FontCollection, RegularFont(font)))
+            .id();
+        app.insert_resource(DefaultFont(collection));
+
+        let text = app
+            .world_mut()
+            .spawn((Text::new("ωω and ok"), ReactiveFont))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world().get::<MissingGlyphs>(text),
+            Some(&MissingGlyphs(vec!['ω']))
+        );
+
+        app.world_mut().get_mut::<Text>(text).unwrap().0 = "ok".into();
+        app.update();
+        assert_eq!(app.world().get::<MissingGlyphs>(text), None);
+    }
+}
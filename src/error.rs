@@ -1,4 +1,10 @@
-use bevy::{ecs::query::QueryEntityError, prelude::*};
+use crate::prelude::*;
+use bevy::{
+    asset::ParseAssetPathError,
+    ecs::{query::QueryEntityError, system::SystemParam},
+    platform::collections::HashSet,
+    prelude::*,
+};
 use thiserror::Error as ThisError;
 
 #[derive(ThisError, Debug)]
@@ -12,3 +18,161 @@ pub enum FontError {
     #[error("Entity {0}, is not a ReactiveFont, {1}")]
     InvalidReactiveFont(Entity, QueryEntityError),
 }
+
+/// What to do when a [`FontError`] happens.
+#[derive(Reflect, PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum ErrorPolicy {
+    /// Do nothing
+    Ignore,
+    /// Log a warning the first time it happens to each entity
+    WarnOnce,
+    /// Panic in debug builds, and warn once in release builds
+    PanicInDebug,
+    /// Pass the error on to bevy's error handler
+    #[default]
+    Propagate,
+}
+
+/// How each kind of [`FontError`] is handled. This can be set through
+/// [`ReactiveFontPlugin::error_policy`] and changed at any time.
+///
+/// Text that runs into one of these errors will have [`UnresolvedFont`] added to it, whatever the
+/// policy is.
+#[derive(Resource, Reflect, PartialEq, Eq, Clone, Default, Debug)]
+pub struct FontErrorPolicy {
+    /// Used for [`FontError::CannotFindFont`]
+    pub cannot_find_font: ErrorPolicy,
+    /// Used for [`FontError::InvalidFont`]
+    pub invalid_font: ErrorPolicy,
+    /// Used for [`FontError::InvalidReactiveFont`]
+    pub invalid_reactive_font: ErrorPolicy,
+}
+impl FontErrorPolicy {
+    /// Uses the same policy for every error
+    pub fn all(policy: ErrorPolicy) -> Self {
+        Self {
+            cannot_find_font: policy,
+            invalid_font: policy,
+            invalid_reactive_font: policy,
+        }
+    }
+    /// The policy used for an error
+    pub fn get(&self, error: &FontError) -> ErrorPolicy {
+        match error {
+            FontError::CannotFindFont { .. } => self.cannot_find_font,
+            FontError::InvalidFont(..) => self.invalid_font,
+            FontError::InvalidReactiveFont(..) => self.invalid_reactive_font,
+        }
+    }
+}
+
+/// The entities that have already been warned about.
+#[derive(Resource, Default)]
+pub(crate) struct WarnedFontErrors(HashSet<Entity>);
+
+/// Handles the result of updating a [`ReactiveFont`] according to the [`FontErrorPolicy`].
+#[derive(SystemParam)]
+pub(crate) struct FontErrors<'w, 's> {
+    commands: Commands<'w, 's>,
+    policy: Res<'w, FontErrorPolicy>,
+    warned: ResMut<'w, WarnedFontErrors>,
+    unresolved: Query<'w, 's, (), With<UnresolvedFont>>,
+}
+impl FontErrors<'_, '_> {
+    pub fn handle(&mut self, text: Entity, result: Result<(), FontError>) -> Result<(), BevyError> {
+        let Err(error) = result else {
+            self.warned.0.remove(&text);
+            if self.unresolved.contains(text) {
+                self.commands.entity(text).try_remove::<UnresolvedFont>();
+            }
+            return Ok(());
+        };
+
+        if !self.unresolved.contains(text) {
+            self.commands.entity(text).try_insert(UnresolvedFont);
+        }
+
+        match self.policy.get(&error) {
+            ErrorPolicy::Ignore => {}
+            ErrorPolicy::PanicInDebug if cfg!(debug_assertions) => panic!("{error}"),
+            ErrorPolicy::WarnOnce | ErrorPolicy::PanicInDebug => {
+                if self.warned.0.insert(text) {
+                    warn!("{error}");
+                }
+            }
+            ErrorPolicy::Propagate => return Err(error.into()),
+        }
+
+        Ok(())
+    }
+}
+
+/// Forgets about text that is no longer a [`ReactiveFont`], including when it's despawned.
+pub(crate) fn forget_warned_font(
+    on_remove: On<Remove, ReactiveFont>,
+    mut warned: ResMut<WarnedFontErrors>,
+) {
+    warned.0.remove(&on_remove.entity);
+}
+
+#[derive(ThisError, PartialEq, Eq, Debug)]
+pub enum MarkupError {
+    #[error("Unknown tag [{0}]")]
+    UnknownTag(String),
+    #[error("A [ was never closed, {0}")]
+    UnclosedBracket(String),
+    #[error("Tag {0} was never closed")]
+    UnclosedTag(String),
+    #[error("Tag [{0}] doesn't close anything")]
+    UnexpectedClosingTag(String),
+    #[error("Expected {expected} to be closed before {found}")]
+    MismatchedTag { expected: String, found: String },
+    #[error("{0} is not a valid hex color")]
+    InvalidColor(String),
+    #[error("{0} is not a valid font size")]
+    InvalidSize(String),
+}
+
+#[derive(ThisError, Debug)]
+pub enum FontCollectionLoaderError {
+    #[error("Unable to read font collection, {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Unable to parse font collection, {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Invalid font path, {0}")]
+    InvalidPath(#[from] ParseAssetPathError),
+    #[error("{0} is not a valid hex color")]
+    InvalidColor(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::ReactiveFontPlugin;
+
+    #[test]
+    fn warnings_are_forgotten_with_their_text() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ReactiveFontPlugin {
+                error_policy: FontErrorPolicy::all(ErrorPolicy::WarnOnce),
+                ..Default::default()
+            },
+        ));
+
+        // Without a `DefaultFont` there's nothing for the text to use.
+        let removed = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+        let despawned = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+        app.update();
+
+        let warned = &app.world().resource::<WarnedFontErrors>().0;
+        assert!(warned.contains(&removed) && warned.contains(&despawned));
+
+        app.world_mut().entity_mut(removed).remove::<ReactiveFont>();
+        app.world_mut().despawn(despawned);
+
+        assert!(app.world().resource::<WarnedFontErrors>().0.is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Every face a [`FontCollection`] has that has been set, including the four required ones.
+pub(crate) fn collection_faces<'a>(
+    regular: &'a RegularFont,
+    italic: &'a ItalicFont,
+    bold: &'a BoldFont,
+    bold_italic: &'a BoldItalicFont,
+    faces: &'a FontFaces,
+) -> impl Iterator<Item = (FontWeight, bool, &'a Handle<Font>)> {
+    // Registered faces come first so that they win out over the required ones when the weights
+    // are the same.
+    faces
+        .iter()
+        .map(|face| (face.weight, face.italic, &face.font))
+        .chain([
+            (FontWeight::NORMAL, false, &regular.0),
+            (FontWeight::NORMAL, true, &italic.0),
+            (FontWeight::BOLD, false, &bold.0),
+            (FontWeight::BOLD, true, &bold_italic.0),
+        ])
+        .filter(|(_, _, font)| is_set(font))
+}
+
+/// Picks the face to use for the desired weight and style.
+///
+/// This walks down [`FontVariant::fallbacks`] until the collection has a face of that variant,
+/// returning the variant that was used. Faces of a variant are matched by weight, so a weight
+/// between two faces picks the one CSS would.
+pub(crate) fn resolve_face<'a>(
+    faces: impl Iterator<Item = (FontWeight, bool, &'a Handle<Font>)>,
+    desired: FontWeight,
+    italic: bool,
+) -> Option<(&'a Handle<Font>, FontVariant)> {
+    let faces = faces.collect::<Vec<_>>();
+    FontVariant::new(desired.is_bold(), italic)
+        .fallbacks()
+        .iter()
+        .find_map(|variant| {
+            // Faces have to be bold or not as well, or bold italic text would use the italic face
+            // before trying the bold one. Regular is the last resort so any weight will do.
+            let matching = faces
+                .iter()
+                .filter(|(weight, italic, _)| {
+                    *italic == variant.is_italic()
+                        && (*variant == FontVariant::Regular
+                            || weight.is_bold() == variant.is_bold())
+                })
+                .map(|(weight, _, font)| (*weight, (*weight, *font)));
+
+            closest_weight(matching, desired).map(|(weight, font)| {
+                (
+                    font,
+                    FontVariant::new(weight.is_bold(), variant.is_italic()),
+                )
+            })
+        })
+}
+
+/// Picks the face that is closest to the desired weight.
+///
+/// This follows the CSS font matching rules:
+/// - Weights between 400 and 500 look upwards to 500, then downwards, then above 500.
+/// - Weights below 400 look downwards, then upwards.
+/// - Weights above 500 look upwards, then downwards.
+pub(crate) fn closest_weight<T>(
+    faces: impl IntoIterator<Item = (FontWeight, T)>,
+    desired: FontWeight,
+) -> Option<T> {
+    faces
+        .into_iter()
+        .min_by_key(|(weight, _)| weight_priority(desired.0, weight.0))
+        .map(|(_, face)| face)
+}
+
+fn weight_priority(desired: u16, weight: u16) -> (u8, u16) {
+    let distance = desired.abs_diff(weight);
+    match desired {
+        400..=500 if (desired..=500).contains(&weight) => (0, distance),
+        400..=500 if weight < desired => (1, distance),
+        400..=500 => (2, distance),
+        ..400 if weight <= desired => (0, distance),
+        ..400 => (1, distance),
+        _ if weight >= desired => (0, distance),
+        _ => (1, distance),
+    }
+}
+
+/// Unset faces are left as [`Handle::default`], which would otherwise render with bevy's default
+/// font.
+fn is_set(font: &Handle<Font>) -> bool {
+    font.id() != AssetId::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::uuid::Uuid;
+
+    fn font(id: u128) -> Handle<Font> {
+        Handle::Uuid(Uuid::from_u128(id), Default::default())
+    }
+
+    fn resolve(
+        faces: &[(u16, bool, Handle<Font>)],
+        desired: u16,
+        italic: bool,
+    ) -> Option<(&Handle<Font>, FontVariant)> {
+        resolve_face(
+            faces
+                .iter()
+                .map(|(weight, italic, font)| (FontWeight(*weight), *italic, font)),
+            FontWeight(desired),
+            italic,
+        )
+    }
+
+    #[test]
+    fn weight_priority_follows_css() {
+        // Between 400 and 500, upwards to 500 first.
+        assert!(weight_priority(450, 500) < weight_priority(450, 400));
+        assert!(weight_priority(400, 500) < weight_priority(400, 300));
+        assert!(weight_priority(450, 300) < weight_priority(450, 600));
+        // Below 400, downwards first.
+        assert!(weight_priority(300, 100) < weight_priority(300, 400));
+        // Above 500, upwards first.
+        assert!(weight_priority(600, 900) < weight_priority(600, 500));
+        assert!(weight_priority(550, 600) < weight_priority(550, 300));
+        // Closer weights win within the same direction.
+        assert!(weight_priority(700, 800) < weight_priority(700, 900));
+        assert_eq!(weight_priority(700, 700), (0, 0));
+    }
+
+    #[test]
+    fn weights_between_faces_match_like_css() {
+        let faces = [(300, false, font(1)), (600, false, font(2))];
+
+        assert_eq!(
+            resolve(&faces, 550, false),
+            Some((&font(2), FontVariant::Bold))
+        );
+        assert_eq!(
+            resolve(&faces, 400, false),
+            Some((&font(1), FontVariant::Regular))
+        );
+        assert_eq!(
+            resolve(&faces, 900, false),
+            Some((&font(2), FontVariant::Bold))
+        );
+    }
+
+    #[test]
+    fn exact_faces_are_used() {
+        let faces = [
+            (400, false, font(1)),
+            (400, true, font(2)),
+            (700, false, font(3)),
+            (700, true, font(4)),
+        ];
+
+        assert_eq!(
+            resolve(&faces, 400, false),
+            Some((&font(1), FontVariant::Regular))
+        );
+        assert_eq!(
+            resolve(&faces, 400, true),
+            Some((&font(2), FontVariant::Italic))
+        );
+        assert_eq!(
+            resolve(&faces, 700, false),
+            Some((&font(3), FontVariant::Bold))
+        );
+        assert_eq!(
+            resolve(&faces, 700, true),
+            Some((&font(4), FontVariant::BoldItalic))
+        );
+    }
+
+    #[test]
+    fn bold_italic_falls_back_to_bold_before_italic() {
+        let faces = [
+            (400, false, font(1)),
+            (400, true, font(2)),
+            (700, false, font(3)),
+        ];
+
+        assert_eq!(
+            resolve(&faces, 700, true),
+            Some((&font(3), FontVariant::Bold))
+        );
+        assert_eq!(
+            resolve(&faces, 400, true),
+            Some((&font(2), FontVariant::Italic))
+        );
+    }
+
+    #[test]
+    fn missing_styles_fall_back_to_upright() {
+        let faces = [(400, false, font(1)), (700, false, font(2))];
+
+        assert_eq!(
+            resolve(&faces, 400, true),
+            Some((&font(1), FontVariant::Regular))
+        );
+        assert_eq!(
+            resolve(&faces, 700, true),
+            Some((&font(2), FontVariant::Bold))
+        );
+    }
+
+    #[test]
+    fn regular_falls_back_to_any_upright_weight() {
+        let faces = [(700, false, font(1))];
+
+        assert_eq!(
+            resolve(&faces, 400, true),
+            Some((&font(1), FontVariant::Bold))
+        );
+    }
+
+    #[test]
+    fn italic_faces_are_not_used_for_upright_text() {
+        let faces = [(400, true, font(1))];
+
+        assert_eq!(resolve(&faces, 400, false), None);
+    }
+}
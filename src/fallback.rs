@@ -0,0 +1,444 @@
+use crate::{inherit::InheritedCollection, prelude::*, resolve::DirtyFonts};
+use bevy::{
+    ecs::{entity::EntityHashSet, system::SystemParam},
+    prelude::*,
+};
+use unicode_script::{Script, UnicodeScript};
+
+/// Other [`FontCollection`]'s to use, in order, for characters this collection doesn't have a
+/// glyph for, such as Japanese or Arabic text.
+///
+/// Text using the collection is split into [`FallbackSpan`]'s so that each character is rendered
+/// by the first collection that has it. Glyphs are checked using the [`RegularFont`] of each
+/// collection, nothing is split until the collection's own [`RegularFont`] has loaded.
+///
+/// While text is split its [`Text`], [`TextSpan`] or `Text2d` is left empty and the content is
+/// moved to [`FallbackText`]. Read it from there, and append to it rather than the empty text, or
+/// the content would be replaced with only what was appended.
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Eq, Clone, Default, Debug)]
+pub struct FallbackCollections(pub Vec<Entity>);
+impl From<Vec<Entity>> for FallbackCollections {
+    fn from(value: Vec<Entity>) -> Self {
+        Self::new(value)
+    }
+}
+impl FallbackCollections {
+    /// Creates a new [`FallbackCollections`]
+    pub fn new(value: Vec<Entity>) -> Self {
+        Self(value)
+    }
+    /// Adds another collection, which will be used if the ones before it don't have a glyph
+    pub fn with(mut self, collection: Entity) -> Self {
+        self.0.push(collection);
+        self
+    }
+}
+
+/// The content of a peice of text that has been split into [`FallbackSpan`]'s.
+///
+/// The text itself is left empty while it's split, so this is where the content can be read from.
+/// Changing this splits the text again, which makes it the place to append to, such as for
+/// dialogue that is typed out. Setting the text as normal replaces all of the content. The content
+/// is moved back to the text once it no longer needs splitting.
+#[derive(Component, Reflect, Deref, DerefMut, PartialEq, Eq, Clone, Debug)]
+pub struct FallbackText(pub String);
+
+/// A span created to render part of a peice of text with one of its [`FallbackCollections`].
+///
+/// It holds the collection whose faces are used, or [`None`] for the parts that the text's own
+/// collection has glyphs for. Everything else, such as the size and color, comes from the text it
+/// was split from.
+#[derive(Component, Reflect, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct FallbackSpan(pub Option<Entity>);
+
+/// The content of [`Text`], [`TextSpan`] and `Text2d` that could be split.
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub(crate) struct TextContents<'w, 's> {
+    ui: Query<'w, 's, (Entity, &'static mut Text), (With<ReactiveFont>, Without<FallbackSpan>)>,
+    spans: Query<
+        'w,
+        's,
+        (Entity, &'static mut TextSpan),
+        (With<ReactiveFont>, Without<FallbackSpan>, Without<Text>),
+    >,
+    #[cfg(feature = "text2d")]
+    world: Query<
+        'w,
+        's,
+        (Entity, &'static mut Text2d),
+        (
+            With<ReactiveFont>,
+            Without<FallbackSpan>,
+            Without<Text>,
+            Without<TextSpan>,
+        ),
+    >,
+}
+impl TextContents<'_, '_> {
+    fn get(&self, entity: Entity) -> Option<&str> {
+        if let Ok((_, text)) = self.ui.get(entity) {
+            return Some(text.as_str());
+        }
+        if let Ok((_, span)) = self.spans.get(entity) {
+            return Some(span.as_str());
+        }
+        #[cfg(feature = "text2d")]
+        if let Ok((_, text)) = self.world.get(entity) {
+            return Some(text.as_str());
+        }
+        None
+    }
+
+    fn content_mut(&mut self, entity: Entity) -> Option<Mut<'_, String>> {
+        if let Ok((_, text)) = self.ui.get_mut(entity) {
+            return Some(text.map_unchanged(|text| &mut text.0));
+        }
+        if let Ok((_, span)) = self.spans.get_mut(entity) {
+            return Some(span.map_unchanged(|span| &mut span.0));
+        }
+        #[cfg(feature = "text2d")]
+        if let Ok((_, text)) = self.world.get_mut(entity) {
+            return Some(text.map_unchanged(|text| &mut text.0));
+        }
+        None
+    }
+
+    /// Empties the text without it counting as a change, so it isn't split again.
+    fn clear(&mut self, entity: Entity) {
+        if let Some(mut content) = self.content_mut(entity) {
+            content.bypass_change_detection().clear();
+        }
+    }
+
+    fn set(&mut self, entity: Entity, value: String) {
+        if let Some(mut content) = self.content_mut(entity) {
+            *content = value;
+        }
+    }
+}
+
+/// The text that has been set since the last time it was checked.
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub(crate) struct ChangedText<'w, 's> {
+    ui: Query<'w, 's, Entity, (With<ReactiveFont>, Without<FallbackSpan>, Changed<Text>)>,
+    spans: Query<
+        'w,
+        's,
+        Entity,
+        (
+            With<ReactiveFont>,
+            Without<FallbackSpan>,
+            Without<Text>,
+            Changed<TextSpan>,
+        ),
+    >,
+    #[cfg(feature = "text2d")]
+    world: Query<
+        'w,
+        's,
+        Entity,
+        (
+            With<ReactiveFont>,
+            Without<FallbackSpan>,
+            Without<Text>,
+            Without<TextSpan>,
+            Changed<Text2d>,
+        ),
+    >,
+    sources: Query<'w, 's, Entity, Changed<FallbackText>>,
+}
+impl ChangedText<'_, '_> {
+    fn collect(&self) -> EntityHashSet {
+        let changed = self.ui.iter().chain(self.spans.iter());
+        #[cfg(feature = "text2d")]
+        let changed = changed.chain(self.world.iter());
+        changed.collect()
+    }
+
+    /// Text whose [`FallbackText`] has changed, including when it was first split.
+    fn sources(&self) -> EntityHashSet {
+        self.sources.iter().collect()
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct FallbackSplitter<'w, 's> {
+    collection: InheritedCollection<'w, 's>,
+    coverage: GlyphCoverage<'w, 's>,
+    fallbacks: Query<'w, 's, &'static FallbackCollections>,
+    sources: Query<'w, 's, &'static FallbackText>,
+    spans: Query<'w, 's, (&'static FallbackSpan, &'static TextSpan)>,
+    children: Query<'w, 's, &'static Children>,
+}
+
+/// Splits any text that has changed, or is using a different collection, into [`FallbackSpan`]'s.
+///
+/// This runs before [`resolve_fonts`](crate::resolve::resolve_fonts) so the spans it creates are
+/// resolved in the same frame.
+pub(crate) fn split_fallback_text(
+    mut commands: Commands,
+    dirty: Res<DirtyFonts>,
+    mut text: ParamSet<(TextContents, ChangedText)>,
+    splitter: FallbackSplitter,
+) {
+    // Nothing can be split without fallbacks, or needs putting back if nothing has been split.
+    if splitter.fallbacks.is_empty() && splitter.sources.is_empty() {
+        return;
+    }
+
+    let changed = text.p1().collect();
+    let changed_sources = text.p1().sources();
+    let mut contents = text.p0();
+
+    let candidates = dirty
+        .fonts()
+        .filter(|entity| !splitter.spans.contains(*entity))
+        .chain(changed_sources)
+        .filter(|entity| !changed.contains(entity))
+        .collect::<EntityHashSet>()
+        .into_iter()
+        .map(|entity| (entity, false))
+        .chain(changed.iter().map(|entity| (*entity, true)))
+        .collect::<Vec<_>>();
+
+    candidates.into_iter().for_each(|(text, is_changed)| {
+        let source = splitter.sources.get(text).ok();
+        let content = match contents.get(text) {
+            Some(content) if !content.is_empty() => content.to_string(),
+            // The text was emptied on purpose, so there's nothing to split anymore.
+            Some(_) if is_changed => String::new(),
+            Some(_) => match source {
+                Some(source) => source.0.clone(),
+                None => return,
+            },
+            None => return,
+        };
+
+        let runs = splitter.runs(text, &content);
+        let spans = splitter.spans_of(text);
+
+        match runs {
+            // It's split the same way as last time, so the spans can be kept.
+            Some(runs) if splitter.matches(&spans, &runs) => {
+                if source.is_none_or(|source| source.0 != content) {
+                    commands.entity(text).try_insert(FallbackText(content));
+                }
+                contents.clear(text);
+            }
+            runs => {
+                spans.into_iter().for_each(|span| {
+                    commands.entity(span).despawn();
+                });
+
+                match runs {
+                    None => {
+                        if source.is_some() {
+                            commands.entity(text).try_remove::<FallbackText>();
+                            if !is_changed {
+                                contents.set(text, content);
+                            }
+                        }
+                    }
+                    Some(runs) => {
+                        contents.clear(text);
+
+                        let spans = runs
+                            .into_iter()
+                            .map(|(run, collection)| {
+                                commands
+                                    .spawn((
+                                        TextSpan::new(run),
+                                        ReactiveFont,
+                                        FallbackSpan(collection),
+                                    ))
+                                    .id()
+                            })
+                            .collect::<Vec<_>>();
+
+                        commands
+                            .entity(text)
+                            .try_insert(FallbackText(content))
+                            .insert_children(0, &spans);
+                    }
+                }
+            }
+        }
+    });
+}
+
+impl FallbackSplitter<'_, '_> {
+    /// The spans from the last time the text was split, in order.
+    fn spans_of(&self, text: Entity) -> Vec<Entity> {
+        self.children
+            .get(text)
+            .into_iter()
+            .flatten()
+            .filter(|child| self.spans.contains(**child))
+            .copied()
+            .collect()
+    }
+
+    /// Whether the spans already hold these runs.
+    fn matches(&self, spans: &[Entity], runs: &[(String, Option<Entity>)]) -> bool {
+        spans.len() == runs.len()
+            && spans.iter().zip(runs).all(|(span, (run, collection))| {
+                self.spans
+                    .get(*span)
+                    .is_ok_and(|(fallback, text)| fallback.0 == *collection && text.as_str() == run)
+            })
+    }
+
+    /// Splits the content into runs by the collection that should render them, [`None`] if the
+    /// text doesn't need splitting.
+    fn runs(&self, text: Entity, content: &str) -> Option<Vec<(String, Option<Entity>)>> {
+        let primary = self.collection.get(text)?;
+        let fallbacks = self.fallbacks.get(primary).ok()?;
+
+        let faces = core::iter::once(primary)
+            .chain(fallbacks.iter().copied())
+            .map(|collection| (collection, self.coverage.face(collection)))
+            .collect::<Vec<_>>();
+
+        // Nothing can be checked until the text's own collection has loaded.
+        faces[0].1.as_ref()?;
+
+        let runs = split_runs(content, faces.len(), |index, character| {
+            faces[index]
+                .1
+                .as_ref()
+                .is_some_and(|face| face.glyph_index(character).is_some())
+        });
+
+        match runs.as_slice() {
+            [] | [(_, 0)] => None,
+            _ => Some(
+                runs.into_iter()
+                    .map(|(run, index)| (run, (index != 0).then_some(faces[index].0)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Splits text into runs, each using the first of the faces that `covers` the characters in it.
+///
+/// Characters shared between scripts, such as spaces, digits and punctuation, stay in the run
+/// before them when its face has them, so they don't break it up. Control characters, and ones no
+/// face has, stay in the run before them too.
+fn split_runs(
+    content: &str,
+    faces: usize,
+    covers: impl Fn(usize, char) -> bool,
+) -> Vec<(String, usize)> {
+    let mut runs: Vec<(String, usize)> = Vec::new();
+
+    content.chars().for_each(|character| {
+        let current = runs.last().map(|(_, index)| *index);
+        let is_shared = matches!(character.script(), Script::Common | Script::Inherited);
+
+        let index = match current {
+            Some(current) if character.is_control() => current,
+            Some(current) if is_shared && covers(current, character) => current,
+            _ => (0..faces)
+                .find(|index| covers(*index, character))
+                .or(current)
+                .unwrap_or_default(),
+        };
+
+        match runs.last_mut() {
+            Some((run, current)) if *current == index => run.push(character),
+            _ => runs.push((character.to_string(), index)),
+        }
+    });
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATIN: usize = 0;
+    const JAPANESE: usize = 1;
+
+    /// A Latin face, and a Japanese face that also has ASCII like most do.
+    fn covers(index: usize, character: char) -> bool {
+        let is_japanese = matches!(
+            character.script(),
+            Script::Hiragana | Script::Katakana | Script::Han
+        ) || matches!(character, '、' | '。');
+
+        match index {
+            LATIN => character.is_ascii() && !character.is_ascii_control(),
+            JAPANESE => is_japanese || (character.is_ascii() && !character.is_ascii_control()),
+            _ => false,
+        }
+    }
+
+    fn split(content: &str) -> Vec<(String, usize)> {
+        split_runs(content, 2, covers)
+    }
+
+    fn runs(runs: &[(&str, usize)]) -> Vec<(String, usize)> {
+        runs.iter()
+            .map(|(run, index)| (run.to_string(), *index))
+            .collect()
+    }
+
+    #[test]
+    fn latin_with_japanese() {
+        assert_eq!(split("Hello"), runs(&[("Hello", LATIN)]));
+        assert_eq!(
+            split("Hello こんにちは world"),
+            runs(&[
+                ("Hello ", LATIN),
+                ("こんにちは ", JAPANESE),
+                ("world", LATIN)
+            ])
+        );
+        assert_eq!(split("日本語"), runs(&[("日本語", JAPANESE)]));
+        assert_eq!(split(""), runs(&[]));
+    }
+
+    #[test]
+    fn shared_characters_at_the_start() {
+        assert_eq!(
+            split("2024 年"),
+            runs(&[("2024 ", LATIN), ("年", JAPANESE)])
+        );
+        assert_eq!(split(" こ"), runs(&[(" ", LATIN), ("こ", JAPANESE)]));
+    }
+
+    #[test]
+    fn shared_characters_stay_in_their_run() {
+        assert_eq!(split("こ 1 2。"), runs(&[("こ 1 2。", JAPANESE)]));
+        assert_eq!(
+            split("a, b こ, d"),
+            runs(&[("a, b ", LATIN), ("こ, ", JAPANESE), ("d", LATIN)])
+        );
+    }
+
+    #[test]
+    fn shared_characters_the_run_doesnt_have() {
+        assert_eq!(
+            split("ab、cd"),
+            runs(&[("ab", LATIN), ("、", JAPANESE), ("cd", LATIN)])
+        );
+    }
+
+    #[test]
+    fn characters_no_face_has() {
+        assert_eq!(split("a☃b"), runs(&[("a☃b", LATIN)]));
+        assert_eq!(split("こ☃"), runs(&[("こ☃", JAPANESE)]));
+        assert_eq!(split("☃こ"), runs(&[("☃", LATIN), ("こ", JAPANESE)]));
+    }
+
+    #[test]
+    fn control_characters() {
+        assert_eq!(split("こ\na"), runs(&[("こ\n", JAPANESE), ("a", LATIN)]));
+        assert_eq!(split("a\tこ"), runs(&[("a\t", LATIN), ("こ", JAPANESE)]));
+        assert_eq!(split("\nこ"), runs(&[("\n", LATIN), ("こ", JAPANESE)]));
+    }
+}
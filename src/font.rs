@@ -1,21 +1,97 @@
-use bevy::{asset::AsAssetId, prelude::*};
+use bevy::{asset::AsAssetId, platform::collections::HashMap, prelude::*};
 
-use crate::persistent_relationship_source::NeverEmptyVec;
+use crate::{
+    load_state::FontCollectionLoadState, persistent_relationship_source::NeverEmptyVec,
+    role::TypeScale, theme::FontTheme,
+};
 
 /// Marks that a peice of text should be italic
+///
+/// Any [`TextSpan`]'s under the text will also be italic.
 #[derive(Component, Reflect, Clone, Default)]
 pub struct Italic;
 
 /// Marks that a peice of text should be bold
+///
+/// Any [`TextSpan`]'s under the text will also be bold, unless they specify a [`FontWeight`].
 #[derive(Component, Reflect, Clone, Default)]
 pub struct Bold;
 
+/// How heavy a peice of text should be, using the same 100 to 900 scale as CSS.
+///
+/// This takes priority over [`Bold`]. If the [`FontCollection`] doesn't have a face for the exact
+/// weight the closest one is used, following the same rules as CSS.
+///
+/// Any [`TextSpan`]'s under the text will use the same weight, unless they specify their own.
+#[derive(
+    Component, Reflect, DerefMut, Deref, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug,
+)]
+pub struct FontWeight(pub u16);
+impl From<u16> for FontWeight {
+    fn from(value: u16) -> Self {
+        Self::new(value)
+    }
+}
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+impl FontWeight {
+    pub const THIN: Self = Self(100);
+    pub const EXTRA_LIGHT: Self = Self(200);
+    pub const LIGHT: Self = Self(300);
+    pub const NORMAL: Self = Self(400);
+    pub const MEDIUM: Self = Self(500);
+    pub const SEMI_BOLD: Self = Self(600);
+    pub const BOLD: Self = Self(700);
+    pub const EXTRA_BOLD: Self = Self(800);
+    pub const BLACK: Self = Self(900);
+
+    /// Creates a new [`FontWeight`]
+    pub fn new(value: u16) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal weight
+    pub fn into_inner(&self) -> u16 {
+        self.0
+    }
+    /// Whether this weight counts as bold, anything from [`FontWeight::SEMI_BOLD`] upwards does.
+    pub fn is_bold(&self) -> bool {
+        *self >= Self::SEMI_BOLD
+    }
+}
+
 /// How large the text should be.
 ///
-/// This will ignore [`DefaultFontSize`] and will stay the same size even if [`DefaultFontSize`]
-/// changes.
-#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Debug)]
-pub struct FontSize(pub f32);
+/// [`FontSize::Px`] will ignore [`DefaultFontSize`] and will stay the same size even if
+/// [`DefaultFontSize`] changes, the other sizes are relative and will follow it.
+///
+/// The viewport sizes follow the size of the camera the text is rendered to, and are updated
+/// whenever it's resized. Use a [`FontSizeClamp`] to keep them within a sensible range.
+///
+/// When placed on a container every [`ReactiveFont`] below it will use this size, unless they
+/// specify their own.
+#[derive(Component, Reflect, PartialEq, Clone, Copy, Debug)]
+pub enum FontSize {
+    /// A size in pixels
+    Px(f32),
+    /// A multiple of the [`DefaultFontSize`] of the [`FontCollection`] the text is using
+    Rem(f32),
+    /// A multiple of the size the text would be without this, which is the size of the container
+    /// it's in
+    Em(f32),
+    /// The same as [`FontSize::Em`], but as a percentage
+    Percent(f32),
+    /// A percentage of the viewport's width
+    Vw(f32),
+    /// A percentage of the viewport's height
+    Vh(f32),
+    /// A percentage of the viewport's width or height, whichever is smaller
+    VMin(f32),
+    /// A percentage of the viewport's width or height, whichever is larger
+    VMax(f32),
+}
 impl From<f32> for FontSize {
     fn from(value: f32) -> Self {
         Self::new(value)
@@ -27,20 +103,115 @@ impl Default for FontSize {
     }
 }
 impl FontSize {
+    /// Creates a new [`FontSize`] in pixels
     pub fn new(value: f32) -> Self {
-        Self(value)
+        Self::Px(value)
     }
-    pub fn into_inner(&self) -> f32 {
-        self.0
+    /// Works out the size in pixels.
+    ///
+    /// `default_size` is the [`DefaultFontSize`] the text is using, `parent_size` is the size
+    /// of the container it's in and `viewport` is the logical size of the viewport it's rendered
+    /// to.
+    pub fn resolve(&self, default_size: f32, parent_size: f32, viewport: Vec2) -> f32 {
+        match self {
+            Self::Px(px) => *px,
+            Self::Rem(rem) => rem * default_size,
+            Self::Em(em) => em * parent_size,
+            Self::Percent(percent) => percent / 100. * parent_size,
+            Self::Vw(vw) => vw / 100. * viewport.x,
+            Self::Vh(vh) => vh / 100. * viewport.y,
+            Self::VMin(vmin) => vmin / 100. * viewport.min_element(),
+            Self::VMax(vmax) => vmax / 100. * viewport.max_element(),
+        }
+    }
+    /// Whether the size depends on the size of the container the text is in.
+    pub fn is_relative_to_parent(&self) -> bool {
+        matches!(self, Self::Em(_) | Self::Percent(_))
+    }
+}
+impl core::str::FromStr for FontSize {
+    type Err = core::num::ParseFloatError;
+
+    /// Parses a size written like CSS, `24`, `24px`, `1.5rem`, `1.5em`, `150%`, `2vw`, `2vh`,
+    /// `2vmin` or `2vmax`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        if let Some(vmin) = value.strip_suffix("vmin") {
+            vmin.trim().parse().map(Self::VMin)
+        } else if let Some(vmax) = value.strip_suffix("vmax") {
+            vmax.trim().parse().map(Self::VMax)
+        } else if let Some(vw) = value.strip_suffix("vw") {
+            vw.trim().parse().map(Self::Vw)
+        } else if let Some(vh) = value.strip_suffix("vh") {
+            vh.trim().parse().map(Self::Vh)
+        } else if let Some(rem) = value.strip_suffix("rem") {
+            rem.trim().parse().map(Self::Rem)
+        } else if let Some(em) = value.strip_suffix("em") {
+            em.trim().parse().map(Self::Em)
+        } else if let Some(percent) = value.strip_suffix('%') {
+            percent.trim().parse().map(Self::Percent)
+        } else {
+            let px = value.strip_suffix("px").unwrap_or(value);
+            px.trim().parse().map(Self::Px)
+        }
+    }
+}
+
+/// Keeps the size of a peice of text between a minimum and maximum, in pixels.
+///
+/// This is applied to the size worked out from the [`FontSize`] on the same entity, or the size
+/// it inherits if it doesn't have one. Any text below it using [`FontSize::Em`] will be relative
+/// to the clamped size.
+#[derive(Component, Reflect, PartialEq, Clone, Copy, Debug)]
+pub struct FontSizeClamp {
+    pub min: f32,
+    pub max: f32,
+}
+impl Default for FontSizeClamp {
+    fn default() -> Self {
+        Self::new(0., f32::INFINITY)
+    }
+}
+impl FontSizeClamp {
+    /// Creates a new [`FontSizeClamp`]
+    pub fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+    /// Only sets a minimum size
+    pub fn min(min: f32) -> Self {
+        Self::new(min, f32::INFINITY)
+    }
+    /// Only sets a maximum size
+    pub fn max(max: f32) -> Self {
+        Self::new(0., max)
+    }
+    /// Clamps a size in pixels
+    pub fn clamp(&self, size: f32) -> f32 {
+        size.max(self.min).min(self.max)
     }
 }
 
 /// What color the text should be.
 ///
-/// This will ignore [`DefaultFontColor`] and will stay the same size even if [`DefaultFontColor`]
+/// This will ignore [`DefaultFontColor`] and will stay the same color even if [`DefaultFontColor`]
 /// changes.
-#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
-pub struct FontColor(pub Color);
+///
+/// When placed on a container every [`ReactiveFont`] below it will use this color, unless they
+/// specify their own.
+#[derive(Component, Reflect, PartialEq, Clone, Debug)]
+pub enum FontColor {
+    Color(Color),
+    /// A color from the active [`FontTheme`] by its name, such as `"primary"`.
+    ///
+    /// If the active theme doesn't have the color it's ignored, as if it wasn't set at all.
+    Theme(String),
+}
+impl Default for FontColor {
+    fn default() -> Self {
+        Self::Color(Color::default())
+    }
+}
 impl<C: Into<Color>> From<C> for FontColor {
     fn from(value: C) -> Self {
         Self::new(value)
@@ -49,27 +220,151 @@ impl<C: Into<Color>> From<C> for FontColor {
 impl FontColor {
     /// Creates a new [`FontColor`]
     pub fn new(value: impl Into<Color>) -> Self {
-        Self(value.into())
+        Self::Color(value.into())
+    }
+    /// Creates a new [`FontColor`] that uses a color from the active [`FontTheme`]
+    pub fn theme(name: impl Into<String>) -> Self {
+        Self::Theme(name.into())
+    }
+    /// Retrives the [`Color`], looking it up in the [`FontTheme`] if needed
+    pub fn resolve(&self, theme: &FontTheme) -> Option<Color> {
+        match self {
+            Self::Color(color) => Some(*color),
+            Self::Theme(name) => theme.get(name),
+        }
+    }
+}
+
+/// The shadow behind a peice of text.
+///
+/// This will ignore [`DefaultTextShadow`] and will stay the same even if [`DefaultTextShadow`]
+/// changes. A [`TextShadow`] inserted directly is only kept until the plugin gives the text a
+/// shadow, so use this instead.
+///
+/// When placed on a container every [`ReactiveFont`] below it will use this shadow, unless they
+/// specify their own.
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
+pub struct FontShadow(pub TextShadow);
+impl FontShadow {
+    /// Creates a new [`FontShadow`]
+    pub fn new(offset: Vec2, color: impl Into<Color>) -> Self {
+        Self(TextShadow {
+            offset,
+            color: color.into(),
+        })
     }
-    /// Retrives internal [`Color`]
-    pub fn into_inner(&self) -> Color {
+    /// Retrives the internal [`TextShadow`]
+    pub fn into_inner(&self) -> TextShadow {
         self.0
     }
 }
 
+/// Stops a peice of text from having a shadow, even if its [`FontCollection`] has a
+/// [`DefaultTextShadow`].
+///
+/// When placed on a container every [`ReactiveFont`] below it won't have a shadow, unless they
+/// specify a [`FontShadow`].
+#[derive(Component, Reflect, Clone, Default)]
+pub struct NoTextShadow;
+
+/// Stops [`FontScale`] and [`CollectionFontScale`] from affecting the size of a peice of text,
+/// for text that needs to stay the same size such as HUD numbers.
+///
+/// When placed on a container every [`ReactiveFont`] below it will keep its size.
+#[derive(Component, Reflect, Clone, Default)]
+pub struct FixedFontSize;
+
+/// The height of each line of text.
+///
+/// This will ignore [`DefaultLineHeight`] and will stay the same even if [`DefaultLineHeight`]
+/// changes.
+///
+/// When placed on a container every [`ReactiveFont`] below it will use this line height, unless
+/// they specify their own.
+#[derive(Component, Reflect, PartialEq, Clone, Copy, Debug)]
+pub enum LineHeight {
+    /// In pixels
+    Px(f32),
+    /// A multiple of the size of the text
+    RelativeToFont(f32),
+}
+impl Default for LineHeight {
+    fn default() -> Self {
+        Self::RelativeToFont(1.2)
+    }
+}
+impl From<LineHeight> for bevy::text::LineHeight {
+    fn from(value: LineHeight) -> Self {
+        match value {
+            LineHeight::Px(px) => Self::Px(px),
+            LineHeight::RelativeToFont(scale) => Self::RelativeToFont(scale),
+        }
+    }
+}
+
+/// Whether the text should be antialiased.
+///
+/// This will ignore [`DefaultFontSmoothing`] and will stay the same even if
+/// [`DefaultFontSmoothing`] changes.
+///
+/// When placed on a container every [`ReactiveFont`] below it will use this, unless they specify
+/// their own.
+#[derive(Component, Reflect, PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum FontSmoothing {
+    /// No antialiasing, for pixel art fonts
+    None,
+    #[default]
+    AntiAliased,
+}
+impl From<FontSmoothing> for bevy::text::FontSmoothing {
+    fn from(value: FontSmoothing) -> Self {
+        match value {
+            FontSmoothing::None => Self::None,
+            FontSmoothing::AntiAliased => Self::AntiAliased,
+        }
+    }
+}
+
 // Font Descriptors
 
 /// A marker component that indicates that a peice of text should be styled by the [`ReactiveFontPlugin`]. Text
 /// without this marker will not be styled
+///
+/// A [`TextSpan`] with this marker will use the font, size and color of the text it's under unless
+/// it specifies its own, so marking a single span as [`Bold`] will only bold that span.
+///
+/// This works on both UI [`Text`] and, with the `text2d` feature, world space `Text2d`.
 #[derive(Component, Reflect, Clone, Default)]
 pub struct ReactiveFont;
 
-/// This font that a [`ReactiveFont`] is using. If this is not specified it will default to
-/// [`DefaultFont`]
+/// This font that a [`ReactiveFont`] is using. If this is not specified it will use the font of
+/// the closest ancestor that has one, and then [`DefaultFont`]
 #[derive(Component, Reflect, Clone, Debug)]
 #[relationship(relationship_target = UsedBy)]
 pub struct UsingFont(pub Entity);
 
+/// Uses the [`FontCollection`] registered under this name in the [`FontRegistry`].
+///
+/// This is turned into a [`UsingFont`] once a collection with the name exists, and will follow the
+/// name if another collection is registered under it later.
+#[derive(Component, Reflect, Deref, DerefMut, PartialEq, Eq, Hash, Clone, Debug)]
+pub struct UseFontNamed(pub String);
+impl<S: Into<String>> From<S> for UseFontNamed {
+    fn from(value: S) -> Self {
+        Self::new(value)
+    }
+}
+impl UseFontNamed {
+    /// Creates a new [`UseFontNamed`]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+    /// Retrives the internal name
+    pub fn into_inner(&self) -> &str {
+        &self.0
+    }
+}
+
 // Font Collections
 
 /// The default font to be used when one is not specified.
@@ -87,19 +382,192 @@ impl DefaultFont {
     }
 }
 
+/// Overrides [`DefaultFont`] for text below a UI root, or for UI rendered to a [`Camera`].
+///
+/// Text uses the collection it picked itself, or one picked by a container it's in, before falling
+/// back to the closest scope and then [`DefaultFont`]. A scope on a container takes priority over
+/// one on the camera.
+#[derive(Component, Reflect, Clone, Debug)]
+pub struct DefaultFontScope(pub Entity);
+impl DefaultFontScope {
+    pub fn new(value: Entity) -> Self {
+        Self(value)
+    }
+    pub fn into_inner(&self) -> Entity {
+        self.0
+    }
+}
+
+/// The font used in place of a [`FontCollection`] that has been despawned.
+///
+/// Any text that was using the despawned collection will be moved over to this one, and it will
+/// replace [`DefaultFont`] if that was the one despawned. Without this text will go back to using
+/// [`DefaultFont`], which is removed if it was the one despawned.
+///
+/// Text using [`UseFontNamed`] is left alone and will use [`DefaultFont`] until another collection
+/// is registered under the name.
+#[derive(Resource, Reflect, Clone, Debug)]
+pub struct FallbackFont(pub Entity);
+impl FallbackFont {
+    pub fn new(value: Entity) -> Self {
+        Self(value)
+    }
+    pub fn into_inner(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Scales the size of every [`ReactiveFont`], including ones with a [`FontSize`].
+///
+/// This is useful for letting players change the size of all text at once. A [`FontCollection`]
+/// with a [`CollectionFontScale`] will use that instead, and text with [`FixedFontSize`] isn't
+/// affected.
+#[derive(Resource, Reflect, DerefMut, Deref, PartialEq, Clone, Debug)]
+pub struct FontScale(pub f32);
+impl Default for FontScale {
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+impl FontScale {
+    /// Creates a new [`FontScale`]
+    pub fn new(value: f32) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal scale
+    pub fn into_inner(&self) -> f32 {
+        self.0
+    }
+}
+
+/// All the [`FontCollection`]'s that have a name, so that they can be referred to with
+/// [`UseFontNamed`] instead of passing around their [`Entity`].
+///
+/// Collections with a [`FontName`] are added and removed automatically, but others can be
+/// inserted manually.
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+pub struct FontRegistry(HashMap<String, Entity>);
+impl FontRegistry {
+    /// Finds the collection registered under a name
+    pub fn get(&self, name: &str) -> Option<Entity> {
+        self.0.get(name).copied()
+    }
+    /// Registers a collection under a name, returning the collection that was previously
+    /// registered under it
+    pub fn insert(&mut self, name: impl Into<String>, font: Entity) -> Option<Entity> {
+        self.0.insert(name.into(), font)
+    }
+    /// Removes a name from the registry, returning the collection that was registered under it
+    pub fn remove(&mut self, name: &str) -> Option<Entity> {
+        self.0.remove(name)
+    }
+    /// Iterates over each name and the collection registered under it
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Entity)> {
+        self.0.iter().map(|(name, font)| (name.as_str(), *font))
+    }
+}
+
+/// The name a [`FontCollection`] is registered under in the [`FontRegistry`].
+///
+/// If another collection already has the name it will be replaced, and will take the name back
+/// if this collection loses it.
+#[derive(Component, Reflect, Deref, PartialEq, Eq, Hash, Clone, Debug)]
+#[component(immutable)]
+pub struct FontName(pub String);
+impl<S: Into<String>> From<S> for FontName {
+    fn from(value: S) -> Self {
+        Self::new(value)
+    }
+}
+impl FontName {
+    /// Creates a new [`FontName`]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+    /// Retrives the internal name
+    pub fn into_inner(&self) -> &str {
+        &self.0
+    }
+}
+
 /// A collection of font information.
+///
+/// Only [`RegularFont`] needs to be set, text asking for a face the collection doesn't have will
+/// fall back along [`FontVariant::fallbacks`] and be given a [`FontFallback`].
 #[derive(Component, Reflect, Clone, Default)]
 #[require(
     RegularFont,
     ItalicFont,
     BoldFont,
     BoldItalicFont,
+    FontFaces,
     DefaultFontSize,
     DefaultFontColor,
+    DefaultLineHeight,
+    DefaultFontSmoothing,
+    TypeScale,
+    FontCollectionLoadState,
     UsedBy
 )]
 pub struct FontCollection;
 
+/// One of the four basic faces a [`FontCollection`] can have.
+#[derive(Reflect, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum FontVariant {
+    Regular,
+    Bold,
+    Italic,
+    BoldItalic,
+}
+impl FontVariant {
+    /// Creates the [`FontVariant`] for the given boldness and style
+    pub fn new(bold: bool, italic: bool) -> Self {
+        match (bold, italic) {
+            (true, true) => Self::BoldItalic,
+            (false, true) => Self::Italic,
+            (true, false) => Self::Bold,
+            (false, false) => Self::Regular,
+        }
+    }
+    pub fn is_bold(&self) -> bool {
+        matches!(self, Self::Bold | Self::BoldItalic)
+    }
+    pub fn is_italic(&self) -> bool {
+        matches!(self, Self::Italic | Self::BoldItalic)
+    }
+    /// The variants to try, in order, when looking for this one.
+    ///
+    /// The full chain is `BoldItalic -> Bold -> Italic -> Regular`, variants only ever fall back to
+    /// ones that drop a style rather than add one.
+    pub fn fallbacks(&self) -> &'static [FontVariant] {
+        match self {
+            Self::BoldItalic => &[Self::BoldItalic, Self::Bold, Self::Italic, Self::Regular],
+            Self::Bold => &[Self::Bold, Self::Regular],
+            Self::Italic => &[Self::Italic, Self::Regular],
+            Self::Regular => &[Self::Regular],
+        }
+    }
+}
+
+/// Added to a [`ReactiveFont`] when its [`FontCollection`] doesn't have the face it asked for and
+/// a fallback had to be used instead.
+///
+/// This is removed once the requested face is available again.
+#[derive(Component, Reflect, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct FontFallback {
+    /// The variant the text asked for
+    pub requested: FontVariant,
+    /// The variant that was actually used
+    pub used: FontVariant,
+}
+
+/// Added to a [`ReactiveFont`] when a font couldn't be found for it, see [`FontErrorPolicy`] for how
+/// the error itself is handled.
+///
+/// This is removed once the font can be found again.
+#[derive(Component, Reflect, Clone, Copy, Default, Debug)]
+pub struct UnresolvedFont;
+
 /// All the text that uses a specific [`FontCollection`]
 #[derive(Component, Reflect, Clone, Default, Debug)]
 #[relationship_target(relationship = UsingFont)]
@@ -197,6 +665,61 @@ impl BoldItalicFont {
     }
 }
 
+/// A single face registered in [`FontFaces`]
+#[derive(Reflect, PartialEq, Eq, Clone, Default, Debug)]
+pub struct FontFace {
+    pub weight: FontWeight,
+    pub italic: bool,
+    pub font: Handle<Font>,
+}
+impl FontFace {
+    /// Creates a new upright [`FontFace`]
+    pub fn new(weight: impl Into<FontWeight>, font: Handle<Font>) -> Self {
+        Self {
+            weight: weight.into(),
+            italic: false,
+            font,
+        }
+    }
+    /// Creates a new italic [`FontFace`]
+    pub fn italic(weight: impl Into<FontWeight>, font: Handle<Font>) -> Self {
+        Self {
+            weight: weight.into(),
+            italic: true,
+            font,
+        }
+    }
+}
+
+/// Extra faces used by a [`FontCollection`] for weights other than regular and bold.
+///
+/// [`RegularFont`] and [`ItalicFont`] count as [`FontWeight::NORMAL`], [`BoldFont`] and
+/// [`BoldItalicFont`] count as [`FontWeight::BOLD`]. A face registered here with the same weight
+/// and style will be used instead of them.
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Eq, Clone, Default, Debug)]
+pub struct FontFaces(pub Vec<FontFace>);
+impl From<Vec<FontFace>> for FontFaces {
+    fn from(value: Vec<FontFace>) -> Self {
+        Self::new(value)
+    }
+}
+impl FontFaces {
+    /// Creates a new [`FontFaces`]
+    pub fn new(value: Vec<FontFace>) -> Self {
+        Self(value)
+    }
+    /// Registers an upright face for the given weight
+    pub fn with_face(mut self, weight: impl Into<FontWeight>, font: Handle<Font>) -> Self {
+        self.0.push(FontFace::new(weight, font));
+        self
+    }
+    /// Registers an italic face for the given weight
+    pub fn with_italic_face(mut self, weight: impl Into<FontWeight>, font: Handle<Font>) -> Self {
+        self.0.push(FontFace::italic(weight, font));
+        self
+    }
+}
+
 /// The default font size for a [`FontCollection`]
 #[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Debug)]
 pub struct DefaultFontSize(pub f32);
@@ -222,15 +745,134 @@ impl DefaultFontSize {
 }
 
 /// The default font color for a [`FontCollection`]
+///
+/// Like [`FontColor`] this can be a color from the active [`FontTheme`], so that switching themes
+/// recolors text that doesn't set its own color.
 #[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
-pub struct DefaultFontColor(pub Color);
+pub struct DefaultFontColor(pub FontColor);
+impl<C: Into<Color>> From<C> for DefaultFontColor {
+    fn from(value: C) -> Self {
+        Self::new(value)
+    }
+}
 impl DefaultFontColor {
     /// Creates a new [`DefaultFontColor`]
     pub fn new(value: impl Into<Color>) -> Self {
-        Self(value.into())
+        Self(FontColor::new(value))
+    }
+    /// Creates a new [`DefaultFontColor`] that uses a color from the active [`FontTheme`]
+    pub fn theme(name: impl Into<String>) -> Self {
+        Self(FontColor::theme(name))
+    }
+    /// Retrives the internal [`FontColor`]
+    pub fn into_inner(&self) -> &FontColor {
+        &self.0
+    }
+}
+
+/// The default shadow for a [`FontCollection`], text using a collection without one doesn't have
+/// a shadow.
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
+pub struct DefaultTextShadow(pub TextShadow);
+impl DefaultTextShadow {
+    /// Creates a new [`DefaultTextShadow`]
+    pub fn new(offset: Vec2, color: impl Into<Color>) -> Self {
+        Self(TextShadow {
+            offset,
+            color: color.into(),
+        })
+    }
+    /// Retrives the internal [`TextShadow`]
+    pub fn into_inner(&self) -> TextShadow {
+        self.0
+    }
+}
+
+/// The default line height for a [`FontCollection`]
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
+pub struct DefaultLineHeight(pub LineHeight);
+impl DefaultLineHeight {
+    /// Creates a new [`DefaultLineHeight`]
+    pub fn new(value: LineHeight) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal [`LineHeight`]
+    pub fn into_inner(&self) -> LineHeight {
+        self.0
     }
-    /// Retrives the internal [`Color`]
-    pub fn into_inner(&self) -> Color {
+}
+
+/// The default font smoothing for a [`FontCollection`]
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Default, Debug)]
+pub struct DefaultFontSmoothing(pub FontSmoothing);
+impl DefaultFontSmoothing {
+    /// Creates a new [`DefaultFontSmoothing`]
+    pub fn new(value: FontSmoothing) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal [`FontSmoothing`]
+    pub fn into_inner(&self) -> FontSmoothing {
         self.0
     }
 }
+
+/// Scales the size of text using a [`FontCollection`], in place of [`FontScale`].
+#[derive(Component, Reflect, DerefMut, Deref, PartialEq, Clone, Debug)]
+pub struct CollectionFontScale(pub f32);
+impl From<f32> for CollectionFontScale {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+impl CollectionFontScale {
+    /// Creates a new [`CollectionFontScale`]
+    pub fn new(value: f32) -> Self {
+        Self(value)
+    }
+    /// Retrives the internal scale
+    pub fn into_inner(&self) -> f32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn font_size_parses_each_unit() {
+        assert_eq!("24".parse(), Ok(FontSize::Px(24.)));
+        assert_eq!("24px".parse(), Ok(FontSize::Px(24.)));
+        assert_eq!("1.5rem".parse(), Ok(FontSize::Rem(1.5)));
+        assert_eq!("1.5em".parse(), Ok(FontSize::Em(1.5)));
+        assert_eq!("150%".parse(), Ok(FontSize::Percent(150.)));
+        assert_eq!("2vw".parse(), Ok(FontSize::Vw(2.)));
+        assert_eq!("2vh".parse(), Ok(FontSize::Vh(2.)));
+        assert_eq!("2vmin".parse(), Ok(FontSize::VMin(2.)));
+        assert_eq!("2vmax".parse(), Ok(FontSize::VMax(2.)));
+        assert_eq!("-0.5em".parse(), Ok(FontSize::Em(-0.5)));
+    }
+
+    #[test]
+    fn font_size_ignores_whitespace() {
+        assert_eq!("  24  ".parse(), Ok(FontSize::Px(24.)));
+        assert_eq!(" 24 px ".parse(), Ok(FontSize::Px(24.)));
+        assert_eq!("1.5 rem".parse(), Ok(FontSize::Rem(1.5)));
+        assert_eq!("150 %".parse(), Ok(FontSize::Percent(150.)));
+        assert_eq!("\t2 vmin\n".parse(), Ok(FontSize::VMin(2.)));
+    }
+
+    #[test]
+    fn font_size_rejects_invalid_input() {
+        [
+            "", " ", "px", "rem", "%", "big", "24pt", "1.5 e m", "em1.5", "2vw2", "1,5em",
+        ]
+        .into_iter()
+        .for_each(|value| {
+            assert!(
+                value.parse::<FontSize>().is_err(),
+                "{value:?} should not parse"
+            );
+        });
+    }
+}
@@ -0,0 +1,389 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::{relationship::Relationship, system::SystemParam},
+    prelude::*,
+};
+
+/// Looks up a component on an entity, falling back to the closest ancestor that has it.
+#[derive(SystemParam)]
+pub(crate) struct Inherited<'w, 's, T: Component> {
+    values: Query<'w, 's, &'static T>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+impl<T: Component> Inherited<'_, '_, T> {
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        core::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .find_map(|entity| self.values.get(entity).ok())
+    }
+}
+
+/// Finds all the [`ReactiveFont`]'s that could inherit from an entity, including the entity
+/// itself.
+#[derive(SystemParam)]
+pub(crate) struct Inheritors<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    reactive_fonts: Query<'w, 's, (), With<ReactiveFont>>,
+}
+impl Inheritors<'_, '_> {
+    pub fn iter(&self, entity: Entity) -> impl Iterator<Item = Entity> {
+        core::iter::once(entity)
+            .chain(self.children.iter_descendants(entity))
+            .filter(|entity| self.reactive_fonts.contains(*entity))
+    }
+}
+
+/// Finds the [`FontCollection`] a peice of text is using.
+///
+/// The closest entity that picks one with [`UsingFont`], or with a [`TextClass`] that names one,
+/// wins. Otherwise it's the closest [`DefaultFontScope`], then the one on the camera the text is
+/// rendered to, then the [`DefaultFont`].
+///
+/// [`AccessibilitySettings::dyslexic_font`] takes priority over all of them.
+#[derive(SystemParam)]
+pub(crate) struct InheritedCollection<'w, 's> {
+    choices: Query<'w, 's, (Option<&'static UsingFont>, Option<&'static TextClass>)>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    style_sheet: Res<'w, TextStyleSheet>,
+    registry: Res<'w, FontRegistry>,
+    scopes: Inherited<'w, 's, DefaultFontScope>,
+    cameras: Inherited<'w, 's, ComputedUiTargetCamera>,
+    camera_scopes: Query<'w, 's, &'static DefaultFontScope, With<Camera>>,
+    default_font: Option<Res<'w, DefaultFont>>,
+    accessibility: Res<'w, AccessibilitySettings>,
+}
+impl InheritedCollection<'_, '_> {
+    pub fn get(&self, entity: Entity) -> Option<Entity> {
+        if let Some(dyslexic_font) = self.accessibility.dyslexic_font {
+            return Some(dyslexic_font);
+        }
+
+        core::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .filter_map(|entity| self.choices.get(entity).ok())
+            .find_map(|(using_font, class)| {
+                using_font.map(UsingFont::get).or_else(|| {
+                    class
+                        .and_then(|class| self.style_sheet.style(class).font)
+                        .and_then(|name| self.registry.get(&name))
+                })
+            })
+            .or_else(|| self.scope(entity))
+            .or(self.default_font.as_ref().map(|font| font.0))
+    }
+
+    /// The collection of the closest [`DefaultFontScope`], or the one on the camera.
+    fn scope(&self, entity: Entity) -> Option<Entity> {
+        self.scopes
+            .get(entity)
+            .or_else(|| {
+                self.cameras
+                    .get(entity)
+                    .and_then(ComputedUiTargetCamera::get)
+                    .and_then(|camera| self.camera_scopes.get(camera).ok())
+            })
+            .map(DefaultFontScope::into_inner)
+    }
+}
+
+/// Finds all the [`ReactiveFont`]'s that could be using a [`FontCollection`], whether they picked
+/// it with [`UsingFont`], through a [`TextClass`] or are using the [`DefaultFont`].
+///
+/// When the collection is [`AccessibilitySettings::dyslexic_font`], or the [`DefaultFontScope`]
+/// of a camera, that's every [`ReactiveFont`].
+#[derive(SystemParam)]
+pub(crate) struct CollectionUsers<'w, 's> {
+    accessibility: Res<'w, AccessibilitySettings>,
+    everyone: Query<'w, 's, Entity, With<ReactiveFont>>,
+    default_font: Option<Res<'w, DefaultFont>>,
+    default_users: Query<'w, 's, Entity, (With<ReactiveFont>, Without<UsingFont>)>,
+    scopes: Query<'w, 's, (Entity, &'static DefaultFontScope, Has<Camera>)>,
+    used_by: Query<'w, 's, &'static UsedBy>,
+    classes: Query<'w, 's, (Entity, &'static TextClass)>,
+    style_sheet: Res<'w, TextStyleSheet>,
+    registry: Res<'w, FontRegistry>,
+    inheritors: Inheritors<'w, 's>,
+    fallback_spans: Query<'w, 's, (Entity, &'static FallbackSpan)>,
+}
+impl CollectionUsers<'_, '_> {
+    pub fn iter(&self, collection: Entity) -> impl Iterator<Item = Entity> {
+        let camera_scope = self
+            .scopes
+            .iter()
+            .any(|(_, scope, is_camera)| is_camera && scope.0 == collection);
+
+        let everyone = (camera_scope || self.accessibility.dyslexic_font == Some(collection))
+            .then(|| self.everyone.iter())
+            .into_iter()
+            .flatten();
+
+        let default_users = self
+            .default_font
+            .as_ref()
+            .is_some_and(|default_font| default_font.0 == collection)
+            .then(|| self.default_users.iter())
+            .into_iter()
+            .flatten();
+
+        let used_by = self
+            .used_by
+            .get(collection)
+            .into_iter()
+            .flat_map(|used_by| used_by.iter());
+
+        let classed = self
+            .classes
+            .iter()
+            .filter(move |(_, class)| {
+                self.style_sheet
+                    .style(class)
+                    .font
+                    .is_some_and(|name| self.registry.get(&name) == Some(collection))
+            })
+            .map(|(entity, _)| entity);
+
+        let scoped = self
+            .scopes
+            .iter()
+            .filter(move |(_, scope, is_camera)| !is_camera && scope.0 == collection)
+            .map(|(entity, _, _)| entity);
+
+        let fallback_spans = self
+            .fallback_spans
+            .iter()
+            .filter(move |(_, span)| span.0 == Some(collection))
+            .map(|(entity, _)| entity);
+
+        everyone
+            .chain(default_users)
+            .chain(
+                used_by
+                    .chain(classed)
+                    .chain(scoped)
+                    .flat_map(|entity| self.inheritors.iter(entity)),
+            )
+            .chain(fallback_spans)
+    }
+}
+
+/// Finds all the [`ReactiveFont`]'s that could be using a [`DefaultFontScope`] on an entity.
+///
+/// For a camera that's every [`ReactiveFont`], rather than working out which are rendered to it.
+#[derive(SystemParam)]
+pub(crate) struct ScopeUsers<'w, 's> {
+    cameras: Query<'w, 's, (), With<Camera>>,
+    everyone: Query<'w, 's, Entity, With<ReactiveFont>>,
+    inheritors: Inheritors<'w, 's>,
+}
+impl ScopeUsers<'_, '_> {
+    pub fn iter(&self, entity: Entity) -> impl Iterator<Item = Entity> {
+        let is_camera = self.cameras.contains(entity);
+
+        let everyone = is_camera
+            .then(|| self.everyone.iter())
+            .into_iter()
+            .flatten();
+        let inheritors = (!is_camera)
+            .then(|| self.inheritors.iter(entity))
+            .into_iter()
+            .flatten();
+
+        everyone.chain(inheritors)
+    }
+}
+
+/// The style an entity sets for itself, without anything it inherits.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct DeclaredStyle {
+    pub size: Option<FontSize>,
+    pub clamp: Option<FontSizeClamp>,
+    pub weight: Option<FontWeight>,
+    pub italic: Option<bool>,
+    pub color: Option<FontColor>,
+    pub line_height: Option<LineHeight>,
+    pub smoothing: Option<FontSmoothing>,
+}
+
+type StyleQuery = (
+    Option<&'static FontSize>,
+    Option<&'static FontSizeClamp>,
+    Option<&'static FontWeight>,
+    Has<Bold>,
+    Has<Italic>,
+    Option<&'static FontColor>,
+    Option<&'static TextRole>,
+    Option<&'static TextClass>,
+    Option<&'static LineHeight>,
+    Option<&'static FontSmoothing>,
+);
+
+/// Looks up the style of a peice of text, following it up through the containers it's in.
+///
+/// Components set directly on an entity take priority over its [`TextClass`], which takes priority
+/// over its [`TextRole`]. Roles are looked up in the [`TypeScale`] of the collection the text is
+/// using.
+#[derive(SystemParam)]
+pub(crate) struct InheritedStyle<'w, 's> {
+    styles: Query<'w, 's, StyleQuery>,
+    style_sheet: Res<'w, TextStyleSheet>,
+    theme: Res<'w, FontTheme>,
+    shadows: Query<'w, 's, (Option<&'static FontShadow>, Has<NoTextShadow>)>,
+    spans: Query<'w, 's, (), With<TextSpan>>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+impl InheritedStyle<'_, '_> {
+    /// The style set on the entity itself.
+    pub fn declared(&self, entity: Entity, type_scale: &TypeScale) -> DeclaredStyle {
+        let Ok((
+            size,
+            clamp,
+            weight,
+            is_bold,
+            is_italic,
+            color,
+            role,
+            class,
+            line_height,
+            smoothing,
+        )) = self.styles.get(entity)
+        else {
+            return DeclaredStyle::default();
+        };
+
+        let class = class
+            .map(|class| self.style_sheet.style(class))
+            .unwrap_or_default();
+        let role = role
+            .and_then(|role| type_scale.get(*role))
+            .cloned()
+            .unwrap_or_default();
+
+        DeclaredStyle {
+            size: size.copied().or(class.size).or(role.size),
+            clamp: clamp.copied(),
+            weight: weight
+                .copied()
+                .or(is_bold.then_some(FontWeight::BOLD))
+                .or(class.bold.map(|bold| match bold {
+                    true => FontWeight::BOLD,
+                    false => FontWeight::NORMAL,
+                }))
+                .or(role.weight),
+            italic: is_italic.then_some(true).or(class.italic).or(role.italic),
+            color: color.cloned().or(class.color).or(role.color),
+            line_height: line_height.copied().or(class.line_height),
+            smoothing: smoothing.copied(),
+        }
+    }
+
+    /// The size in pixels, following relative [`FontSize`]'s and [`FontSizeClamp`]'s up through
+    /// the containers the text is in.
+    ///
+    /// `default_size` is used when nothing above the text sets one.
+    pub fn size(
+        &self,
+        entity: Entity,
+        type_scale: &TypeScale,
+        default_size: f32,
+        viewport: Vec2,
+    ) -> f32 {
+        let mut sizes = Vec::new();
+
+        // Only sizes up to the first one that doesn't depend on its container matter.
+        for style in self.ancestors(entity) {
+            let style = self.declared(style, type_scale);
+            sizes.push((style.size, style.clamp));
+            if style.size.is_some_and(|size| !size.is_relative_to_parent()) {
+                break;
+            }
+        }
+
+        sizes
+            .into_iter()
+            .rev()
+            .fold(default_size, |parent_size, (size, clamp)| {
+                let size = size.map_or(parent_size, |size| {
+                    size.resolve(default_size, parent_size, viewport)
+                });
+                clamp.map_or(size, |clamp| clamp.clamp(size))
+            })
+    }
+
+    /// The weight and whether the text should be italic.
+    ///
+    /// A [`TextSpan`] is part of the same paragraph as the text it's under, so it takes its weight
+    /// and style from it unless it specifies its own.
+    pub fn weight(&self, entity: Entity, type_scale: &TypeScale) -> (FontWeight, bool) {
+        let mut weight = None;
+        let mut italic = None;
+
+        self.paragraph(entity)
+            .map(|entity| self.declared(entity, type_scale))
+            .for_each(|style| {
+                weight = weight.or(style.weight);
+                italic = italic.or(style.italic);
+            });
+
+        (
+            weight.unwrap_or(FontWeight::NORMAL),
+            italic.unwrap_or_default(),
+        )
+    }
+
+    /// The color of the text, `default_color` is used when nothing above it sets one.
+    ///
+    /// Colors that aren't in the active [`FontTheme`] are skipped.
+    pub fn color(
+        &self,
+        entity: Entity,
+        type_scale: &TypeScale,
+        default_color: &FontColor,
+    ) -> Option<Color> {
+        self.ancestors(entity)
+            .find_map(|entity| {
+                self.declared(entity, type_scale)
+                    .color
+                    .and_then(|color| color.resolve(&self.theme))
+            })
+            .or_else(|| default_color.resolve(&self.theme))
+    }
+
+    /// The shadow of the text, `default_shadow` is used when nothing above it sets or disables
+    /// one.
+    pub fn shadow(&self, entity: Entity, default_shadow: Option<TextShadow>) -> Option<TextShadow> {
+        self.ancestors(entity)
+            .find_map(|entity| match self.shadows.get(entity) {
+                Ok((_, true)) => Some(None),
+                Ok((Some(shadow), false)) => Some(Some(shadow.0)),
+                _ => None,
+            })
+            .unwrap_or(default_shadow)
+    }
+
+    /// The line height of the text, if anything above it sets one.
+    pub fn line_height(&self, entity: Entity, type_scale: &TypeScale) -> Option<LineHeight> {
+        self.ancestors(entity)
+            .find_map(|entity| self.declared(entity, type_scale).line_height)
+    }
+
+    /// The font smoothing of the text, if anything above it sets one.
+    pub fn smoothing(&self, entity: Entity, type_scale: &TypeScale) -> Option<FontSmoothing> {
+        self.ancestors(entity)
+            .find_map(|entity| self.declared(entity, type_scale).smoothing)
+    }
+
+    /// The entity, followed by all the containers it's in.
+    fn ancestors(&self, entity: Entity) -> impl Iterator<Item = Entity> {
+        core::iter::once(entity).chain(self.parents.iter_ancestors(entity))
+    }
+
+    /// The entity, followed by the spans and text it's a part of.
+    fn paragraph(&self, entity: Entity) -> impl Iterator<Item = Entity> {
+        core::iter::successors(Some(entity), |entity| {
+            self.spans
+                .contains(*entity)
+                .then(|| self.parents.get(*entity).ok().map(ChildOf::parent))
+                .flatten()
+        })
+    }
+}
@@ -1,5 +1,18 @@
+pub mod accessibility;
+pub mod asset;
+pub mod class;
+pub mod coverage;
 pub mod error;
+mod face;
+pub mod fallback;
 pub mod font;
+mod inherit;
+pub mod load_state;
+pub mod locale;
+pub mod markup;
 mod persistent_relationship_source;
 pub mod plugin;
 pub mod prelude;
+mod resolve;
+pub mod role;
+pub mod theme;
@@ -0,0 +1,169 @@
+use crate::prelude::*;
+use bevy::{asset::LoadState, prelude::*};
+
+/// Triggered on a [`FontCollection`] once all of its faces have finished loading.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct FontCollectionLoaded(pub Entity);
+
+/// How far along loading a single face of a [`FontCollection`] is.
+#[derive(Reflect, PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum FaceLoadState {
+    /// The face hasn't been set, so there's nothing to load
+    #[default]
+    Unset,
+    Loading,
+    Loaded,
+    Failed,
+}
+impl FaceLoadState {
+    /// Finds how far along loading a font is
+    pub fn new(
+        font: &Handle<Font>,
+        asset_server: &AssetServer,
+        fonts: Option<&Assets<Font>>,
+    ) -> Self {
+        if font.id() == AssetId::default() {
+            return Self::Unset;
+        }
+
+        match asset_server.get_load_state(font) {
+            Some(LoadState::Loaded) => Self::Loaded,
+            Some(LoadState::Failed(_)) => Self::Failed,
+            Some(LoadState::NotLoaded | LoadState::Loading) => Self::Loading,
+            // Fonts added directly to `Assets<Font>` aren't tracked by the asset server.
+            None if fonts.is_some_and(|fonts| fonts.contains(font)) => Self::Loaded,
+            None => Self::Loading,
+        }
+    }
+}
+
+/// The load state of each face of a [`FontCollection`].
+#[derive(Reflect, PartialEq, Eq, Clone, Default, Debug)]
+pub struct FaceLoadStates {
+    pub regular: FaceLoadState,
+    pub italic: FaceLoadState,
+    pub bold: FaceLoadState,
+    pub bold_italic: FaceLoadState,
+    /// The load state of each face in [`FontFaces`], in the same order.
+    pub faces: Vec<FaceLoadState>,
+}
+impl FaceLoadStates {
+    /// Iterates over the load state of every face
+    pub fn iter(&self) -> impl Iterator<Item = FaceLoadState> {
+        [self.regular, self.italic, self.bold, self.bold_italic]
+            .into_iter()
+            .chain(self.faces.iter().copied())
+    }
+}
+
+/// Whether all the faces of a [`FontCollection`] have finished loading.
+///
+/// This is kept up to date by the [`ReactiveFontPlugin`], [`FontCollectionLoaded`] is triggered
+/// whenever it becomes [`FontCollectionLoadState::Loaded`].
+#[derive(Component, Reflect, PartialEq, Eq, Clone, Debug)]
+pub enum FontCollectionLoadState {
+    /// At least one face is still loading
+    Loading(FaceLoadStates),
+    /// Every face that has been set has loaded
+    Loaded(FaceLoadStates),
+    /// At least one face failed to load, this isn't checked again until the faces are changed
+    Failed(FaceLoadStates),
+}
+impl Default for FontCollectionLoadState {
+    fn default() -> Self {
+        Self::Loading(FaceLoadStates::default())
+    }
+}
+impl FontCollectionLoadState {
+    /// Works out the overall load state from the state of each face
+    pub fn new(faces: FaceLoadStates) -> Self {
+        if faces.iter().any(|face| face == FaceLoadState::Failed) {
+            Self::Failed(faces)
+        } else if faces.iter().any(|face| face == FaceLoadState::Loading) {
+            Self::Loading(faces)
+        } else {
+            Self::Loaded(faces)
+        }
+    }
+    /// The load state of each face
+    pub fn faces(&self) -> &FaceLoadStates {
+        match self {
+            Self::Loading(faces) | Self::Loaded(faces) | Self::Failed(faces) => faces,
+        }
+    }
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading(_))
+    }
+    pub fn is_loaded(&self) -> bool {
+        matches!(self, Self::Loaded(_))
+    }
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+}
+
+/// A run condition that checks if every [`FontCollection`] has loaded.
+///
+/// This is false until at least one collection has been spawned. A collection that failed to load
+/// will keep this from ever being true, check for [`FontCollectionLoadState::Failed`] if that needs
+/// handling.
+pub fn all_font_collections_loaded(collections: Query<&FontCollectionLoadState>) -> bool {
+    !collections.is_empty() && collections.iter().all(FontCollectionLoadState::is_loaded)
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_font_collection_load_state(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    fonts: Option<Res<Assets<Font>>>,
+    mut collections: Query<(
+        Entity,
+        Ref<RegularFont>,
+        Ref<ItalicFont>,
+        Ref<BoldFont>,
+        Ref<BoldItalicFont>,
+        Ref<FontFaces>,
+        Option<&FontCollectionHandle>,
+        &mut FontCollectionLoadState,
+    )>,
+) {
+    let fonts = fonts.as_deref();
+
+    collections.iter_mut().for_each(
+        |(entity, regular, italic, bold, bold_italic, faces, manifest, mut load_state)| {
+            let handles_changed = regular.is_changed()
+                || italic.is_changed()
+                || bold.is_changed()
+                || bold_italic.is_changed()
+                || faces.is_changed();
+
+            // There's nothing left to wait for until the faces are changed.
+            if (load_state.is_loaded() || load_state.is_failed()) && !handles_changed {
+                return;
+            }
+
+            let state = |font: &Handle<Font>| FaceLoadState::new(font, &asset_server, fonts);
+            let faces = FaceLoadStates {
+                regular: state(&regular.0),
+                italic: state(&italic.0),
+                bold: state(&bold.0),
+                bold_italic: state(&bold_italic.0),
+                faces: faces.iter().map(|face| state(&face.font)).collect(),
+            };
+
+            // The faces won't have been set until the file they're listed in has loaded.
+            let new_state = match manifest.map(|manifest| asset_server.get_load_state(&manifest.0))
+            {
+                None | Some(Some(LoadState::Loaded)) => FontCollectionLoadState::new(faces),
+                Some(Some(LoadState::Failed(_))) => FontCollectionLoadState::Failed(faces),
+                Some(_) => FontCollectionLoadState::Loading(faces),
+            };
+
+            if new_state.is_loaded() && !load_state.is_loaded() {
+                commands.trigger(FontCollectionLoaded(entity));
+            }
+
+            load_state.set_if_neq(new_state);
+        },
+    );
+}
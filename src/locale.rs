@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// The [`FontCollection`] a locale uses, either the collection itself or its [`FontName`].
+#[derive(Reflect, PartialEq, Eq, Clone, Debug)]
+pub enum LocaleFont {
+    Collection(Entity),
+    Named(String),
+}
+impl From<Entity> for LocaleFont {
+    fn from(value: Entity) -> Self {
+        Self::Collection(value)
+    }
+}
+impl From<&str> for LocaleFont {
+    fn from(value: &str) -> Self {
+        Self::Named(value.to_string())
+    }
+}
+impl From<String> for LocaleFont {
+    fn from(value: String) -> Self {
+        Self::Named(value)
+    }
+}
+impl LocaleFont {
+    /// Retrives the collection, [`None`] if nothing is registered under the name
+    pub fn get(&self, registry: &FontRegistry) -> Option<Entity> {
+        match self {
+            Self::Collection(collection) => Some(*collection),
+            Self::Named(name) => registry.get(name),
+        }
+    }
+}
+
+/// The font collection to use for each locale, by its tag such as `"en"`, `"ja"` or `"ar"`.
+///
+/// The collection of the active locale is set as the [`DefaultFont`], so switching locale will
+/// update any text that doesn't pick its own collection, along with the sizes and colors that come
+/// from its [`TypeScale`]. A locale without a collection leaves the [`DefaultFont`] as it is, until
+/// its collection is spawned or registered.
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+pub struct FontLocale {
+    active: String,
+    fonts: HashMap<String, LocaleFont>,
+}
+impl FontLocale {
+    /// Creates a new [`FontLocale`] without any collections, where `active` will be used once its
+    /// collection is added
+    pub fn new(active: impl Into<String>) -> Self {
+        Self {
+            active: active.into(),
+            fonts: HashMap::default(),
+        }
+    }
+    /// Sets the collection of a locale
+    pub fn with_font(mut self, locale: impl Into<String>, font: impl Into<LocaleFont>) -> Self {
+        self.fonts.insert(locale.into(), font.into());
+        self
+    }
+    /// The tag of the active locale
+    pub fn active(&self) -> &str {
+        &self.active
+    }
+    /// Switches to another locale
+    pub fn set_active(&mut self, locale: impl Into<String>) {
+        self.active = locale.into();
+    }
+    /// The collection of the active locale, if it has one
+    pub fn font(&self) -> Option<&LocaleFont> {
+        self.fonts.get(&self.active)
+    }
+    /// The collection of a locale
+    pub fn get(&self, locale: &str) -> Option<&LocaleFont> {
+        self.fonts.get(locale)
+    }
+    /// Sets the collection of a locale, returning the previous one
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        font: impl Into<LocaleFont>,
+    ) -> Option<LocaleFont> {
+        self.fonts.insert(locale.into(), font.into())
+    }
+    /// Removes the collection of a locale, returning it
+    pub fn remove(&mut self, locale: &str) -> Option<LocaleFont> {
+        self.fonts.remove(locale)
+    }
+    /// Iterates over each locale and its collection
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &LocaleFont)> {
+        self.fonts
+            .iter()
+            .map(|(locale, font)| (locale.as_str(), font))
+    }
+}
+
+/// Sets the [`DefaultFont`] to the collection of the active locale, which updates the text that
+/// uses it.
+pub(crate) fn font_locale_changed(
+    mut commands: Commands,
+    locale: Res<FontLocale>,
+    registry: Res<FontRegistry>,
+    default_font: Option<ResMut<DefaultFont>>,
+    collections: Query<(), With<FontCollection>>,
+) {
+    let Some(collection) = locale
+        .font()
+        .and_then(|font| font.get(&registry))
+        .filter(|collection| collections.contains(*collection))
+    else {
+        return;
+    };
+
+    match default_font {
+        Some(mut default_font) if default_font.0 != collection => {
+            default_font.0 = collection;
+        }
+        Some(_) => {}
+        None => commands.insert_resource(DefaultFont(collection)),
+    }
+}
@@ -0,0 +1,585 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Updates the spans of the [`Markup`] it is triggered on.
+#[derive(EntityEvent)]
+pub struct UpdateMarkup(Entity);
+
+/// Text written with a small amount of markup, which is turned into [`TextSpan`] children.
+///
+/// The text this is placed on should be left empty, each section of the markup is spawned as a
+/// [`MarkupSpan`] with [`Bold`], [`Italic`], [`FontColor`], [`FontSize`] and [`UseFontNamed`] set
+/// to match. The spans are respawned whenever the markup changes.
+///
+/// The following is supported, a `\` can be used to escape `*` and `[`.
+/// - `**bold**` or `[b]bold[/b]`
+/// - `*italic*` or `[i]italic[/i]`
+/// - `[color=#ff0000]red[/color]`
+/// - `[size=24]large[/size]`, which also takes the same units as [`FontSize`], `[size=1.5em]`
+/// - `[font=name]different font[/font]`, which uses [`UseFontNamed`]
+///
+/// A `*` or `**` that is never closed is left as text, so `5 * 3` is fine to write. Markup that
+/// can't be parsed, such as an unknown tag, is shown as plain text with a warning saying what the
+/// [`MarkupError`] was.
+#[derive(Component, Reflect, Deref, DerefMut, PartialEq, Eq, Clone, Default, Debug)]
+#[require(ReactiveFont)]
+pub struct Markup(pub String);
+impl<S: Into<String>> From<S> for Markup {
+    fn from(value: S) -> Self {
+        Self::new(value)
+    }
+}
+impl Markup {
+    /// Creates a new [`Markup`]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+    /// Retrives the internal text
+    pub fn into_inner(&self) -> &str {
+        &self.0
+    }
+    /// Splits the markup into sections that share the same style.
+    pub fn segments(&self) -> Result<Vec<MarkupSegment>, MarkupError> {
+        parse_markup(&self.0)
+    }
+}
+
+/// Marks a [`TextSpan`] that was spawned from [`Markup`]
+#[derive(Component, Reflect, Clone, Default)]
+pub struct MarkupSpan;
+
+/// A section of [`Markup`] where all the text shares the same style.
+#[derive(PartialEq, Clone, Default, Debug)]
+pub struct MarkupSegment {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<Color>,
+    pub size: Option<FontSize>,
+    pub font: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+enum Tag {
+    Bold,
+    Italic,
+    Color(Color),
+    Size(FontSize),
+    Font(String),
+}
+
+/// Markup once it has been split up, with each opening tag knowing whether it's been closed.
+#[derive(Debug)]
+enum Event<'a> {
+    Text(String),
+    /// A tag along with the delimiter used to open it, so it can only be closed by the same one.
+    Open(&'a str, Tag),
+    Close,
+}
+
+/// A tag that hasn't been closed yet, `emphasis` is the `*` or `**` used to open it.
+struct Opened {
+    event: usize,
+    emphasis: Option<&'static str>,
+}
+
+/// Splits markup into sections that share the same style, see [`Markup`] for the syntax.
+pub fn parse_markup(markup: &str) -> Result<Vec<MarkupSegment>, MarkupError> {
+    let events = parse_events(markup)?;
+
+    let mut segments = Vec::new();
+    let mut tags: Vec<&Tag> = Vec::new();
+    let mut text = String::new();
+
+    events.iter().for_each(|event| match event {
+        Event::Text(event) => text.push_str(event),
+        Event::Open(_, tag) => {
+            push_segment(&mut segments, &mut text, &tags);
+            tags.push(tag);
+        }
+        Event::Close => {
+            push_segment(&mut segments, &mut text, &tags);
+            tags.pop();
+        }
+    });
+
+    push_segment(&mut segments, &mut text, &tags);
+
+    Ok(segments)
+}
+
+fn parse_events(markup: &str) -> Result<Vec<Event<'_>>, MarkupError> {
+    let mut events = Vec::new();
+    let mut opened: Vec<Opened> = Vec::new();
+    let mut chars = markup.char_indices().peekable();
+
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    push_text(&mut events, escaped);
+                }
+            }
+            '*' => {
+                let mut stars = 1;
+                while chars.next_if(|(_, char)| *char == '*').is_some() {
+                    stars += 1;
+                }
+                emphasis(&mut events, &mut opened, stars);
+            }
+            '[' => {
+                let rest = &markup[index + 1..];
+                let end = rest
+                    .find(']')
+                    .ok_or_else(|| MarkupError::UnclosedBracket(rest.to_string()))?;
+                // Skip past the contents of the tag.
+                (0..rest[..=end].chars().count()).for_each(|_| {
+                    chars.next();
+                });
+                bracket_tag(&mut events, &mut opened, &rest[..end])?;
+            }
+            _ => push_text(&mut events, char),
+        }
+    }
+
+    // Only tags in brackets need closing, a stray `*` is just text.
+    if let Some(Opened { event, .. }) = opened.iter().rev().find(|opened| opened.emphasis.is_none())
+        && let Event::Open(open, _) = events[*event]
+    {
+        return Err(MarkupError::UnclosedTag(open.to_string()));
+    }
+    unclosed_emphasis(&mut events, &mut opened, 0);
+
+    Ok(events)
+}
+
+fn push_text(events: &mut Vec<Event>, char: char) {
+    match events.last_mut() {
+        Some(Event::Text(text)) => text.push(char),
+        _ => events.push(Event::Text(char.to_string())),
+    }
+}
+
+/// Closes or opens bold and italic for a run of `*`'s.
+///
+/// A run closes the closest unclosed `*` or `**` of the same length, or failing that the closest
+/// shorter one, so `***` can close both at once. Anything left over opens new tags.
+fn emphasis(events: &mut Vec<Event>, opened: &mut Vec<Opened>, mut stars: usize) {
+    while stars > 0 {
+        // Emphasis can't be closed from outside the bracket tag it was opened in.
+        let open = opened
+            .iter()
+            .rev()
+            .take_while(|opened| opened.emphasis.is_some())
+            .map(|opened| opened.emphasis.map_or(0, str::len))
+            .enumerate();
+        let found = open
+            .clone()
+            .find(|(_, len)| *len == stars)
+            .or_else(|| open.clone().find(|(_, len)| *len < stars));
+
+        let Some((depth, len)) = found else {
+            break;
+        };
+
+        unclosed_emphasis(events, opened, opened.len() - depth);
+        opened.pop();
+        events.push(Event::Close);
+        stars -= len;
+    }
+
+    while stars > 0 {
+        let (delimiter, tag) = if stars >= 2 {
+            ("**", Tag::Bold)
+        } else {
+            ("*", Tag::Italic)
+        };
+        opened.push(Opened {
+            event: events.len(),
+            emphasis: Some(delimiter),
+        });
+        events.push(Event::Open(delimiter, tag));
+        stars -= delimiter.len();
+    }
+}
+
+/// Turns every `*` or `**` that was opened after `from` back into text, as it was never closed.
+fn unclosed_emphasis(events: &mut [Event], opened: &mut Vec<Opened>, from: usize) {
+    opened.drain(from..).for_each(|opened| {
+        if let Some(delimiter) = opened.emphasis {
+            events[opened.event] = Event::Text(delimiter.to_string());
+        }
+    });
+}
+
+fn bracket_tag<'a>(
+    events: &mut Vec<Event<'a>>,
+    opened: &mut Vec<Opened>,
+    tag: &'a str,
+) -> Result<(), MarkupError> {
+    if let Some(name) = tag.strip_prefix('/') {
+        let open = opened.iter().rposition(|opened| opened.emphasis.is_none());
+        let Some(open) = open else {
+            return Err(MarkupError::UnexpectedClosingTag(tag.to_string()));
+        };
+        let Event::Open(delimiter, _) = events[opened[open].event] else {
+            unreachable!("Opened tags always point to an opening event");
+        };
+        if tag_name(delimiter) != name {
+            return Err(MarkupError::MismatchedTag {
+                expected: delimiter.to_string(),
+                found: tag.to_string(),
+            });
+        }
+
+        unclosed_emphasis(events, opened, open + 1);
+        opened.pop();
+        events.push(Event::Close);
+        return Ok(());
+    }
+
+    let (name, value) = tag
+        .split_once('=')
+        .map(|(name, value)| (name.trim(), Some(value.trim())))
+        .unwrap_or((tag.trim(), None));
+
+    let parsed = match (name, value) {
+        ("b", None) => Tag::Bold,
+        ("i", None) => Tag::Italic,
+        ("color", Some(value)) => Srgba::hex(value)
+            .map(|color| Tag::Color(color.into()))
+            .map_err(|_| MarkupError::InvalidColor(value.to_string()))?,
+        ("size", Some(value)) => value
+            .parse()
+            .map(Tag::Size)
+            .map_err(|_| MarkupError::InvalidSize(value.to_string()))?,
+        ("font", Some(value)) => Tag::Font(value.to_string()),
+        _ => return Err(MarkupError::UnknownTag(tag.to_string())),
+    };
+
+    opened.push(Opened {
+        event: events.len(),
+        emphasis: None,
+    });
+    events.push(Event::Open(tag, parsed));
+    Ok(())
+}
+
+fn push_segment(segments: &mut Vec<MarkupSegment>, text: &mut String, tags: &[&Tag]) {
+    if text.is_empty() {
+        return;
+    }
+
+    let mut segment = MarkupSegment {
+        text: core::mem::take(text),
+        ..Default::default()
+    };
+
+    tags.iter().for_each(|tag| match tag {
+        Tag::Bold => segment.bold = true,
+        Tag::Italic => segment.italic = true,
+        Tag::Color(color) => segment.color = Some(*color),
+        Tag::Size(size) => segment.size = Some(*size),
+        Tag::Font(font) => segment.font = Some(font.clone()),
+    });
+
+    segments.push(segment);
+}
+
+/// The name used to close a tag, `[color=#ff0000]` is closed by `[/color]`.
+fn tag_name(open: &str) -> &str {
+    open.split_once('=')
+        .map(|(name, _)| name)
+        .unwrap_or(open)
+        .trim()
+}
+
+pub(crate) fn on_add_markup(on_add: On<Add, Markup>, mut commands: Commands) {
+    commands.entity(on_add.entity).trigger(UpdateMarkup);
+}
+
+pub(crate) fn changed_markup(mut commands: Commands, changed: Populated<Entity, Changed<Markup>>) {
+    changed.iter().for_each(|entity| {
+        commands.entity(entity).trigger(UpdateMarkup);
+    });
+}
+
+pub(crate) fn update_markup(
+    update: On<UpdateMarkup>,
+    mut commands: Commands,
+    markup: Query<(&Markup, Option<&Children>)>,
+    markup_spans: Query<(), With<MarkupSpan>>,
+) {
+    let Ok((markup, children)) = markup.get(update.0) else {
+        // Happens when the entity has been despawned, ignore it.
+        return;
+    };
+
+    // Show the text as it was written rather than nothing at all. Markup often comes from
+    // translations or dialogue, so a mistake in it shouldn't bring down the app.
+    let segments = markup.segments().unwrap_or_else(|error| {
+        warn!("Showing the markup of {} as plain text, {error}", update.0);
+        vec![MarkupSegment {
+            text: markup.0.clone(),
+            ..Default::default()
+        }]
+    });
+
+    children
+        .into_iter()
+        .flatten()
+        .filter(|child| markup_spans.contains(**child))
+        .for_each(|child| {
+            commands.entity(*child).despawn();
+        });
+
+    segments.into_iter().for_each(|segment| {
+        let mut span = commands.spawn((
+            TextSpan::new(segment.text),
+            ReactiveFont,
+            MarkupSpan,
+            ChildOf(update.0),
+        ));
+
+        if segment.bold {
+            span.insert(Bold);
+        }
+        if segment.italic {
+            span.insert(Italic);
+        }
+        if let Some(color) = segment.color {
+            span.insert(FontColor::Color(color));
+        }
+        if let Some(size) = segment.size {
+            span.insert(size);
+        }
+        if let Some(font) = segment.font {
+            span.insert(UseFontNamed(font));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::ReactiveFontPlugin;
+    use bevy::asset::uuid::Uuid;
+
+    fn text(text: &str) -> MarkupSegment {
+        MarkupSegment {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn bold(text: &str) -> MarkupSegment {
+        MarkupSegment {
+            bold: true,
+            ..self::text(text)
+        }
+    }
+
+    fn italic(text: &str) -> MarkupSegment {
+        MarkupSegment {
+            italic: true,
+            ..self::text(text)
+        }
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(parse_markup("Hello"), Ok(vec![text("Hello")]));
+        assert_eq!(parse_markup(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn bold_and_italic() {
+        assert_eq!(
+            parse_markup("a **b** *c*"),
+            Ok(vec![text("a "), bold("b"), text(" "), italic("c")])
+        );
+        assert_eq!(
+            parse_markup("[b]b[/b][i]c[/i]"),
+            Ok(vec![bold("b"), italic("c")])
+        );
+    }
+
+    #[test]
+    fn bold_italic() {
+        let both = MarkupSegment {
+            bold: true,
+            italic: true,
+            ..text("x")
+        };
+
+        assert_eq!(parse_markup("***x***"), Ok(vec![both.clone()]));
+        assert_eq!(parse_markup("**a *x***"), Ok(vec![bold("a "), both]));
+    }
+
+    #[test]
+    fn color() {
+        assert_eq!(
+            parse_markup("[color=#ff0000]red[/color]"),
+            Ok(vec![MarkupSegment {
+                color: Some(Srgba::RED.into()),
+                ..text("red")
+            }])
+        );
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(
+            parse_markup("[size=24]a[/size][size=1.5em]b[/size]"),
+            Ok(vec![
+                MarkupSegment {
+                    size: Some(FontSize::Px(24.)),
+                    ..text("a")
+                },
+                MarkupSegment {
+                    size: Some(FontSize::Em(1.5)),
+                    ..text("b")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn font() {
+        assert_eq!(
+            parse_markup("[font=mono]m[/font]"),
+            Ok(vec![MarkupSegment {
+                font: Some("mono".to_string()),
+                ..text("m")
+            }])
+        );
+    }
+
+    #[test]
+    fn nested_tags() {
+        assert_eq!(
+            parse_markup("[color=#ff0000]**a**[/color]"),
+            Ok(vec![MarkupSegment {
+                bold: true,
+                color: Some(Srgba::RED.into()),
+                ..text("a")
+            }])
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(parse_markup(r"\*a\* \[b]"), Ok(vec![text("*a* [b]")]));
+    }
+
+    #[test]
+    fn unclosed_stars_are_text() {
+        assert_eq!(parse_markup("5 * 3"), Ok(vec![text("5 * 3")]));
+        assert_eq!(parse_markup("**wait"), Ok(vec![text("**wait")]));
+        assert_eq!(parse_markup("*a* * b"), Ok(vec![italic("a"), text(" * b")]));
+    }
+
+    #[test]
+    fn crossed_stars_are_text() {
+        assert_eq!(
+            parse_markup("**a *b** c*"),
+            Ok(vec![bold("a *b"), text(" c*")])
+        );
+        assert_eq!(parse_markup("[b]*a[/b]"), Ok(vec![bold("*a")]));
+    }
+
+    #[test]
+    fn unknown_tag() {
+        assert_eq!(
+            parse_markup("[u]a[/u]"),
+            Err(MarkupError::UnknownTag("u".to_string()))
+        );
+        assert_eq!(
+            parse_markup("[b=1]a[/b]"),
+            Err(MarkupError::UnknownTag("b=1".to_string()))
+        );
+    }
+
+    #[test]
+    fn unclosed_bracket() {
+        assert_eq!(
+            parse_markup("a [b"),
+            Err(MarkupError::UnclosedBracket("b".to_string()))
+        );
+    }
+
+    #[test]
+    fn unclosed_tag() {
+        assert_eq!(
+            parse_markup("[b]a"),
+            Err(MarkupError::UnclosedTag("b".to_string()))
+        );
+    }
+
+    #[test]
+    fn unexpected_closing_tag() {
+        assert_eq!(
+            parse_markup("a[/b]"),
+            Err(MarkupError::UnexpectedClosingTag("/b".to_string()))
+        );
+    }
+
+    #[test]
+    fn mismatched_tag() {
+        assert_eq!(
+            parse_markup("[b]a[/i]"),
+            Err(MarkupError::MismatchedTag {
+                expected: "b".to_string(),
+                found: "/i".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_color() {
+        assert_eq!(
+            parse_markup("[color=red]a[/color]"),
+            Err(MarkupError::InvalidColor("red".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_size() {
+        assert_eq!(
+            parse_markup("[size=big]a[/size]"),
+            Err(MarkupError::InvalidSize("big".to_string()))
+        );
+    }
+
+    #[test]
+    fn invalid_markup_is_shown_as_text() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ReactiveFontPlugin::default(),
+        ));
+        let font = Handle::Uuid(Uuid::from_u128(1), Default::default());
+        let collection = app
+            .world_mut()
+            .spawn((FontCollection, RegularFont(font)))
+            .id();
+        app.insert_resource(DefaultFont(collection));
+
+        let markup = app
+            .world_mut()
+            .spawn((Text::default(), Markup::new("Press [Enter]")))
+            .id();
+        app.update();
+
+        let world = app.world();
+        let spans = world
+            .get::<Children>(markup)
+            .unwrap()
+            .iter()
+            .filter_map(|child| world.get::<TextSpan>(child))
+            .map(|span| span.0.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(spans, ["Press [Enter]"]);
+    }
+}
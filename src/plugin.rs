@@ -1,27 +1,91 @@
-use crate::prelude::*;
-use bevy::{
-    ecs::{query::QueryEntityError, relationship::Relationship},
+use crate::{
+    asset::font_collection_asset_changed,
+    coverage::report_missing_glyphs,
+    error::{WarnedFontErrors, forget_warned_font},
+    fallback::split_fallback_text,
+    inherit::{CollectionUsers, Inheritors, ScopeUsers},
+    load_state::update_font_collection_load_state,
+    locale::font_locale_changed,
+    markup::{changed_markup, on_add_markup, update_markup},
     prelude::*,
+    resolve::{DirtyFonts, resolve_fonts},
 };
+#[cfg(feature = "text2d")]
+use bevy::window::{PrimaryWindow, WindowResized};
+use bevy::{ecs::relationship::Relationship, prelude::*, text::Text2dUpdateSystems, ui::UiSystems};
 
 /// Updates the font for the entity it is triggered on.
+///
+/// This happens in [`ReactiveFontSystems::Resolve`], along with any other text that has changed.
 #[derive(EntityEvent)]
-pub struct UpdateFont(Entity);
+pub struct UpdateFont(pub Entity);
 
 /// Updates the [`FontSize`] for the entity it is triggered on.
+///
+/// This happens in [`ReactiveFontSystems::Resolve`], along with any other text that has changed.
 #[derive(EntityEvent)]
-pub struct UpdateFontSize(Entity);
+pub struct UpdateFontSize(pub Entity);
 
 /// Updates the [`FontColor`] for the entity it is triggered on.
+///
+/// This happens in [`ReactiveFontSystems::Resolve`], along with any other text that has changed.
 #[derive(EntityEvent)]
-pub struct UpdateFontColor(Entity);
+pub struct UpdateFontColor(pub Entity);
+
+/// The systems added by the [`ReactiveFontPlugin`].
+#[derive(SystemSet, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ReactiveFontSystems {
+    /// Updates all the text that has changed this frame in one go.
+    ///
+    /// Runs in [`PostUpdate`], after the UI has found the camera it's rendered to but before bevy
+    /// lays out any UI or `Text2d` text.
+    Resolve,
+}
 
 /// A plugin that manages [`ReactiveFont`]'s and [`FontCollection`]'s
-pub struct ReactiveFontPlugin;
+#[derive(Default)]
+pub struct ReactiveFontPlugin {
+    /// How errors that happen while updating text are handled
+    pub error_policy: FontErrorPolicy,
+    /// Whether to check text for characters its font doesn't have, giving it [`MissingGlyphs`].
+    ///
+    /// This is meant for debugging, as it has to look through every character of the text.
+    pub report_missing_glyphs: bool,
+}
 
 impl Plugin for ReactiveFontPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(on_add_reactive_font)
+        app.init_resource::<FontRegistry>()
+            .insert_resource(self.error_policy.clone())
+            .init_resource::<WarnedFontErrors>()
+            .init_resource::<DirtyFonts>()
+            .init_resource::<FontScale>()
+            .init_resource::<TextStyleSheet>()
+            .init_resource::<FontTheme>()
+            .init_resource::<AccessibilitySettings>()
+            .init_resource::<FontLocale>()
+            .configure_sets(
+                PostUpdate,
+                ReactiveFontSystems::Resolve
+                    .after(UiSystems::Propagate)
+                    .before(UiSystems::Content)
+                    .before(Text2dUpdateSystems),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    viewport_changed,
+                    target_camera_changed,
+                    split_fallback_text,
+                    resolve_fonts,
+                )
+                    .chain()
+                    .in_set(ReactiveFontSystems::Resolve),
+            )
+            .init_asset::<FontCollectionAsset>()
+            .init_asset_loader::<FontCollectionLoader>()
+            .add_observer(on_add_reactive_font)
+            .add_observer(forget_warned_font)
             .add_systems(
                 Update,
                 (
@@ -31,270 +95,833 @@ impl Plugin for ReactiveFontPlugin {
                     default_font_color_changed,
                 ),
             )
+            .add_systems(PreUpdate, font_collection_asset_changed)
+            .add_systems(Update, update_font_collection_load_state)
             // Font Handles
             .add_observer(selected_font)
             .add_observer(deselected_font)
             .add_observer(on_add_font_tag)
             .add_observer(on_remove_font_tag)
+            .add_systems(Update, changed_font_weight)
             .add_observer(update_font)
+            .add_observer(on_remove_font_collection)
+            // Font Names
+            .add_observer(on_insert_font_name)
+            .add_observer(on_replace_font_name)
+            .add_observer(on_insert_use_font_named)
+            .add_systems(
+                Update,
+                (
+                    changed_use_font_named,
+                    font_registry_changed.run_if(resource_changed::<FontRegistry>),
+                ),
+            )
+            // Locales
+            .add_systems(
+                Update,
+                // A locale's collection may be spawned after it was set.
+                font_locale_changed
+                    .run_if(
+                        resource_changed::<FontLocale>
+                            .or(resource_changed::<FontRegistry>)
+                            .or(any_match_filter::<Added<FontCollection>>),
+                    )
+                    .before(default_font_changed),
+            )
+            // Scopes
+            .add_observer(on_add_default_font_scope)
+            .add_systems(Update, changed_default_font_scope)
+            .add_observer(on_remove_default_font_scope)
+            // Hierarchy
+            .add_observer(on_insert_child_of)
+            .add_observer(on_remove_child_of)
             // Font Size
             .add_observer(on_add_font_size)
             .add_systems(Update, changed_font_size)
             .add_observer(on_remove_font_size)
             .add_observer(update_font_size)
+            // Font Scale
+            .add_systems(
+                Update,
+                font_scale_changed.run_if(resource_changed::<FontScale>),
+            )
+            .add_observer(on_remove_collection_font_scale)
+            .add_observer(on_add_fixed_font_size)
+            .add_observer(on_remove_fixed_font_size)
+            // Roles
+            .add_observer(on_add_text_role)
+            .add_systems(Update, (changed_text_role, type_scale_changed))
+            .add_observer(on_remove_text_role)
+            // Classes
+            .add_observer(on_add_text_class)
+            .add_systems(
+                Update,
+                (
+                    changed_text_class,
+                    style_sheet_changed.run_if(
+                        resource_changed::<TextStyleSheet>.or(resource_changed::<FontRegistry>),
+                    ),
+                ),
+            )
+            .add_observer(on_remove_text_class)
             // Font Color
             .add_observer(on_add_font_color)
             .add_systems(Update, changed_font_color)
             .add_observer(on_remove_font_color)
-            .add_observer(update_font_color);
+            .add_observer(update_font_color)
+            .add_observer(on_remove_default_text_shadow)
+            .add_systems(
+                Update,
+                font_theme_changed.run_if(resource_changed::<FontTheme>),
+            )
+            // Fallbacks
+            .add_systems(Update, changed_fallback_collections)
+            .add_observer(on_remove_fallback_collections)
+            .add_observer(fallback_collection_loaded)
+            // Accessibility
+            .add_systems(
+                Update,
+                accessibility_settings_changed.run_if(resource_changed::<AccessibilitySettings>),
+            )
+            // Markup
+            .add_observer(on_add_markup)
+            .add_systems(Update, changed_markup)
+            .add_observer(update_markup);
+
+        if self.report_missing_glyphs {
+            app.add_systems(
+                PostUpdate,
+                (
+                    report_missing_glyphs::<Text>,
+                    report_missing_glyphs::<TextSpan>,
+                    #[cfg(feature = "text2d")]
+                    report_missing_glyphs::<Text2d>,
+                )
+                    .after(ReactiveFontSystems::Resolve)
+                    .run_if(resource_exists::<Assets<Font>>),
+            );
+        }
+
+        // The window plugin may not have been added, such as in tests.
+        #[cfg(feature = "text2d")]
+        app.add_message::<WindowResized>().add_systems(
+            PostUpdate,
+            window_resized
+                .before(resolve_fonts)
+                .in_set(ReactiveFontSystems::Resolve),
+        );
     }
 }
 
-fn on_add_reactive_font(on_add: On<Add, ReactiveFont>, mut commands: Commands) {
-    commands
-        .entity(on_add.entity)
-        .trigger(UpdateFont)
-        .trigger(UpdateFontSize)
-        .trigger(UpdateFontColor);
+fn on_add_reactive_font(on_add: On<Add, ReactiveFont>, mut dirty: ResMut<DirtyFonts>) {
+    dirty.all(on_add.entity);
 }
 
 fn default_font_changed(
-    mut commands: Commands,
+    mut dirty: ResMut<DirtyFonts>,
     fonts: Populated<Entity, (With<ReactiveFont>, Without<UsingFont>)>,
 ) {
+    // The size and color come from the collection as well.
     fonts.iter().for_each(|entity| {
-        commands.entity(entity).trigger(UpdateFont);
+        dirty.all(entity);
     });
 }
 
 #[allow(clippy::type_complexity)]
 fn font_handle_changed(
-    mut commands: Commands,
-    default_font: Option<Res<DefaultFont>>,
-    fonts: Query<Entity, (With<ReactiveFont>, Without<UsingFont>)>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
     font_handles: Populated<
-        &UsedBy,
-        Or<(
-            Changed<RegularFont>,
-            Changed<BoldFont>,
-            Changed<ItalicFont>,
-            Changed<BoldItalicFont>,
-        )>,
+        Entity,
+        (
+            With<FontCollection>,
+            Or<(
+                Changed<RegularFont>,
+                Changed<BoldFont>,
+                Changed<ItalicFont>,
+                Changed<BoldItalicFont>,
+                Changed<FontFaces>,
+            )>,
+        ),
     >,
 ) {
-    // If the default font has changed, update all fonts that are using it
-    if default_font.is_some_and(|default_font| font_handles.contains(default_font.0)) {
-        fonts.iter().for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFont);
-        })
-    }
-
     font_handles
         .iter()
-        .flat_map(|used_by| used_by.iter())
+        .flat_map(|collection| users.iter(collection))
         .for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFont);
+            dirty.font(entity);
         });
 }
 
+#[allow(clippy::type_complexity)]
 fn default_font_size_changed(
-    mut commands: Commands,
-    default_font: Option<Res<DefaultFont>>,
-    fonts: Query<Entity, (With<ReactiveFont>, Without<UsingFont>)>,
-    font_handles: Populated<&UsedBy, Changed<DefaultFontSize>>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+    font_handles: Populated<
+        Entity,
+        (
+            With<FontCollection>,
+            Or<(
+                Changed<DefaultFontSize>,
+                Changed<DefaultLineHeight>,
+                Changed<DefaultFontSmoothing>,
+                Changed<CollectionFontScale>,
+            )>,
+        ),
+    >,
 ) {
-    // If the default font has changed, update all fonts that are using it
-    if default_font.is_some_and(|default_font| font_handles.contains(default_font.0)) {
-        fonts.iter().for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFontSize);
+    font_handles
+        .iter()
+        .flat_map(|collection| users.iter(collection))
+        .for_each(|entity| {
+            dirty.size(entity);
         });
-    }
+}
 
+#[allow(clippy::type_complexity)]
+fn default_font_color_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+    font_handles: Populated<
+        Entity,
+        (
+            With<FontCollection>,
+            Or<(Changed<DefaultFontColor>, Changed<DefaultTextShadow>)>,
+        ),
+    >,
+) {
     font_handles
         .iter()
-        .flat_map(|used_by| used_by.iter())
+        .flat_map(|collection| users.iter(collection))
         .for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFontSize);
+            dirty.color(entity);
         });
 }
 
-fn default_font_color_changed(
+// Font Handles
+
+// The font size and color come from the collection as well, so they need updating too.
+fn selected_font(
+    on_insert: On<Insert, UsingFont>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_insert.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+fn deselected_font(
+    on_remove: On<Remove, UsingFont>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+// Spans take their weight and style from the text they're under, so they need updating too.
+
+fn on_add_font_tag(
+    on_add: On<Add, (Bold, Italic, FontWeight)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.font(entity);
+    });
+}
+
+fn on_remove_font_tag(
+    on_remove: On<Remove, (Bold, Italic, FontWeight)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.font(entity);
+    });
+}
+
+fn changed_font_weight(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, (With<ReactiveFont>, Changed<FontWeight>)>,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.font(entity);
+        });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn on_remove_font_collection(
+    on_remove: On<Remove, FontCollection>,
     mut commands: Commands,
+    used_by: Query<&UsedBy>,
+    named: Query<(), With<UseFontNamed>>,
+    scopes: Query<(Entity, &DefaultFontScope)>,
     default_font: Option<Res<DefaultFont>>,
-    fonts: Query<Entity, (With<ReactiveFont>, Without<UsingFont>)>,
-    font_handles: Populated<&UsedBy, Changed<DefaultFontColor>>,
+    fallback_font: Option<Res<FallbackFont>>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    mut dirty: ResMut<DirtyFonts>,
+    default_users: Query<Entity, (With<ReactiveFont>, Without<UsingFont>)>,
 ) {
-    // If the default font has changed, update all fonts that are using it
-    if default_font.is_some_and(|default_font| font_handles.contains(default_font.0)) {
-        fonts.iter().for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFontColor);
-        });
+    let removed = on_remove.entity;
+
+    if accessibility.dyslexic_font == Some(removed) {
+        accessibility.dyslexic_font = None;
     }
 
-    font_handles
+    // The fallback can't be used if it's the one being removed.
+    let fallback = match fallback_font {
+        Some(fallback_font) if fallback_font.0 == removed => {
+            commands.remove_resource::<FallbackFont>();
+            None
+        }
+        fallback_font => fallback_font.map(|fallback_font| fallback_font.0),
+    };
+
+    if default_font.is_some_and(|default_font| default_font.0 == removed) {
+        match fallback {
+            Some(fallback) => commands.insert_resource(DefaultFont(fallback)),
+            None => {
+                // `default_font_changed` won't see the resource being removed, so the text has
+                // to find out it can't be resolved here.
+                commands.remove_resource::<DefaultFont>();
+                default_users.iter().for_each(|user| dirty.all(user));
+            }
+        }
+    }
+
+    scopes
         .iter()
+        .filter(|(_, scope)| scope.0 == removed)
+        .for_each(|(entity, _)| match fallback {
+            Some(fallback) => {
+                commands
+                    .entity(entity)
+                    .try_insert(DefaultFontScope(fallback));
+            }
+            None => {
+                commands.entity(entity).try_remove::<DefaultFontScope>();
+            }
+        });
+
+    // When the collection is despawned bevy will remove `UsingFont` itself, but not when only the
+    // component is removed.
+    used_by
+        .get(removed)
+        .into_iter()
         .flat_map(|used_by| used_by.iter())
-        .for_each(|entity| {
-            commands.entity(entity).trigger(UpdateFontColor);
+        .filter(|user| !named.contains(*user))
+        .for_each(|user| match fallback {
+            Some(fallback) => {
+                commands.entity(user).try_insert(UsingFont(fallback));
+            }
+            None => {
+                commands.entity(user).try_remove::<UsingFont>();
+            }
         });
 }
 
-// Font Handles
+fn update_font(update: On<UpdateFont>, mut dirty: ResMut<DirtyFonts>) {
+    dirty.font(update.0);
+}
 
-fn selected_font(on_add: On<Add, UsingFont>, mut commands: Commands) {
-    commands.entity(on_add.entity).trigger(UpdateFont);
+// Font Names
+
+fn on_insert_font_name(
+    on_insert: On<Insert, FontName>,
+    names: Query<&FontName>,
+    mut registry: ResMut<FontRegistry>,
+) {
+    if let Ok(name) = names.get(on_insert.entity) {
+        registry.insert(name.0.clone(), on_insert.entity);
+    }
 }
 
-fn deselected_font(on_remove: On<Remove, UsingFont>, mut commands: Commands) {
-    commands.entity(on_remove.entity).trigger(UpdateFont);
+fn on_replace_font_name(
+    on_replace: On<Replace, FontName>,
+    names: Query<(Entity, &FontName)>,
+    mut registry: ResMut<FontRegistry>,
+) {
+    // Another collection might have taken the name since.
+    let Ok((_, name)) = names.get(on_replace.entity) else {
+        return;
+    };
+    if registry.get(name) != Some(on_replace.entity) {
+        return;
+    }
+
+    // Any other collection that still has the name takes its place.
+    match names
+        .iter()
+        .find(|(entity, other)| *entity != on_replace.entity && *other == name)
+    {
+        Some((other, _)) => registry.insert(name.0.clone(), other),
+        None => registry.remove(name),
+    };
 }
 
-fn on_add_font_tag(on_add: On<Add, (Bold, Italic)>, mut commands: Commands) {
-    commands.entity(on_add.entity).trigger(UpdateFont);
+fn on_insert_use_font_named(
+    on_insert: On<Insert, UseFontNamed>,
+    mut commands: Commands,
+    registry: Res<FontRegistry>,
+    named: Query<(&UseFontNamed, Option<&UsingFont>)>,
+) {
+    if let Ok((name, using_font)) = named.get(on_insert.entity) {
+        select_named_font(&mut commands, &registry, on_insert.entity, name, using_font);
+    }
 }
 
-fn on_remove_font_tag(on_remove: On<Remove, (Bold, Italic)>, mut commands: Commands) {
-    commands.entity(on_remove.entity).trigger(UpdateFont);
+fn changed_use_font_named(
+    mut commands: Commands,
+    registry: Res<FontRegistry>,
+    named: Populated<(Entity, &UseFontNamed, Option<&UsingFont>), Changed<UseFontNamed>>,
+) {
+    named.iter().for_each(|(entity, name, using_font)| {
+        select_named_font(&mut commands, &registry, entity, name, using_font);
+    });
 }
 
-#[allow(clippy::type_complexity)]
-fn update_font(
-    update: On<UpdateFont>,
-    mut reactive_fonts: Populated<(&mut TextFont, Has<Italic>, Has<Bold>, Option<&UsingFont>)>,
-    fonts: Populated<(&RegularFont, &ItalicFont, &BoldFont, &BoldItalicFont), With<FontCollection>>,
-    default_font: Option<Res<DefaultFont>>,
-) -> Result<(), BevyError> {
-    if let Err(QueryEntityError::EntityDoesNotExist(_)) = reactive_fonts.get_mut(update.0) {
-        // Happens when the entity has been despawned, ignore it.
-        return Ok(());
+fn font_registry_changed(
+    mut commands: Commands,
+    registry: Res<FontRegistry>,
+    named: Populated<(Entity, &UseFontNamed, Option<&UsingFont>)>,
+) {
+    named.iter().for_each(|(entity, name, using_font)| {
+        select_named_font(&mut commands, &registry, entity, name, using_font);
+    });
+}
+
+fn select_named_font(
+    commands: &mut Commands,
+    registry: &FontRegistry,
+    entity: Entity,
+    name: &UseFontNamed,
+    using_font: Option<&UsingFont>,
+) {
+    let current_font = using_font.map(UsingFont::get);
+
+    match registry.get(name) {
+        Some(font) if current_font != Some(font) => {
+            commands.entity(entity).try_insert(UsingFont(font));
+        }
+        // The collection is gone, so fall back to whatever it would use otherwise.
+        None if current_font.is_some() => {
+            commands.entity(entity).try_remove::<UsingFont>();
+        }
+        _ => {}
     }
+}
 
-    let (mut text_font, is_italic, is_bold, using_font) = reactive_fonts
-        .get_mut(update.0)
-        .map_err(|err| FontError::InvalidReactiveFont(update.0, err))?;
-
-    let current_font = using_font
-        .map(UsingFont::get)
-        .or(default_font.map(|font| font.0))
-        .ok_or(FontError::CannotFindFont { text: update.0 })?;
-
-    let (
-        RegularFont(regular_font),
-        ItalicFont(italic_font),
-        BoldFont(bold_font),
-        BoldItalicFont(bold_italic_font),
-    ) = fonts
-        .get(current_font)
-        .map_err(|err| FontError::InvalidFont(current_font, err))?;
-
-    let font = match (is_italic, is_bold) {
-        (true, true) => bold_italic_font,
-        (true, _) => italic_font,
-        (_, true) => bold_font,
-        _ => regular_font,
-    };
+// Scopes
+
+fn on_add_default_font_scope(
+    on_add: On<Add, DefaultFontScope>,
+    mut dirty: ResMut<DirtyFonts>,
+    scope_users: ScopeUsers,
+) {
+    scope_users.iter(on_add.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
 
-    text_font.font = font.clone();
+fn changed_default_font_scope(
+    mut dirty: ResMut<DirtyFonts>,
+    scope_users: ScopeUsers,
+    changed: Populated<Entity, Changed<DefaultFontScope>>,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| scope_users.iter(entity))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
+}
 
-    Ok(())
+fn on_remove_default_font_scope(
+    on_remove: On<Remove, DefaultFontScope>,
+    mut dirty: ResMut<DirtyFonts>,
+    scope_users: ScopeUsers,
+) {
+    scope_users.iter(on_remove.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+// Text moved to another camera might be using a different scope.
+fn target_camera_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, (With<ReactiveFont>, Changed<ComputedUiTargetCamera>)>,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
+}
+
+// Hierarchy
+
+fn on_insert_child_of(
+    on_insert: On<Insert, ChildOf>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_insert.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+fn on_remove_child_of(
+    on_remove: On<Remove, ChildOf>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
 }
 
 // Font Size
 
-fn on_add_font_size(on_add: On<Add, FontSize>, mut commands: Commands) {
-    commands.entity(on_add.entity).trigger(UpdateFontSize);
+// The line height and smoothing are updated along with the size.
+
+fn on_add_font_size(
+    on_add: On<Add, (FontSize, FontSizeClamp, LineHeight, FontSmoothing)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.size(entity);
+    });
 }
 
+#[allow(clippy::type_complexity)]
 fn changed_font_size(
-    mut commands: Commands,
-    changed: Populated<Entity, (With<ReactiveFont>, Changed<FontSize>)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<
+        Entity,
+        Or<(
+            Changed<FontSize>,
+            Changed<FontSizeClamp>,
+            Changed<LineHeight>,
+            Changed<FontSmoothing>,
+        )>,
+    >,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.size(entity);
+        });
+}
+
+fn on_remove_font_size(
+    on_remove: On<Remove, (FontSize, FontSizeClamp, LineHeight, FontSmoothing)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
 ) {
-    changed.iter().for_each(|entity| {
-        commands.entity(entity).trigger(UpdateFontSize);
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.size(entity);
     });
 }
 
-fn on_remove_font_size(on_remove: On<Remove, FontSize>, mut commands: Commands) {
-    commands.entity(on_remove.entity).trigger(UpdateFontSize);
+// Viewport sizes need updating when the camera they're rendered to is resized.
+fn viewport_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, (With<ReactiveFont>, Changed<ComputedUiRenderTargetInfo>)>,
+) {
+    // Spans don't have their own render target, so they follow the text they're in.
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.size(entity);
+        });
 }
 
-fn update_font_size(
-    update: On<UpdateFontSize>,
-    mut reactive_fonts: Query<(&mut TextFont, Option<&FontSize>, Option<&UsingFont>)>,
-    fonts: Query<&DefaultFontSize, With<FontCollection>>,
-    default_font: Option<Res<DefaultFont>>,
-) -> Result<(), BevyError> {
-    if let Err(QueryEntityError::EntityDoesNotExist(_)) = reactive_fonts.get_mut(update.0) {
-        // Happens when the entity has been despawned, ignore it.
-        return Ok(());
+// `Text2d` viewport sizes follow the primary window instead.
+#[cfg(feature = "text2d")]
+fn window_resized(
+    mut dirty: ResMut<DirtyFonts>,
+    mut resized: MessageReader<WindowResized>,
+    primary_window: Query<(), With<PrimaryWindow>>,
+    fonts: Query<Entity, (With<ReactiveFont>, Without<ComputedUiRenderTargetInfo>)>,
+) {
+    if !resized
+        .read()
+        .any(|resized| primary_window.contains(resized.window))
+    {
+        return;
     }
 
-    let (mut text_font, font_size, using_font) = reactive_fonts
-        .get_mut(update.0)
-        .map_err(|err| FontError::InvalidReactiveFont(update.0, err))?;
+    fonts.iter().for_each(|entity| {
+        dirty.size(entity);
+    });
+}
+
+fn update_font_size(update: On<UpdateFontSize>, mut dirty: ResMut<DirtyFonts>) {
+    dirty.size(update.0);
+}
+
+// Font Scale
+
+fn font_scale_changed(mut dirty: ResMut<DirtyFonts>, fonts: Populated<Entity, With<ReactiveFont>>) {
+    fonts.iter().for_each(|entity| {
+        dirty.size(entity);
+    });
+}
+
+fn on_remove_collection_font_scale(
+    on_remove: On<Remove, CollectionFontScale>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+) {
+    users.iter(on_remove.entity).for_each(|entity| {
+        dirty.size(entity);
+    });
+}
+
+fn on_add_fixed_font_size(
+    on_add: On<Add, FixedFontSize>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.size(entity);
+    });
+}
+
+fn on_remove_fixed_font_size(
+    on_remove: On<Remove, FixedFontSize>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.size(entity);
+    });
+}
+
+// Roles
+
+// Roles can set the weight, size and color, so they all need updating.
+
+fn on_add_text_role(
+    on_add: On<Add, TextRole>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+fn changed_text_role(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, Changed<TextRole>>,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
+}
+
+fn on_remove_text_role(
+    on_remove: On<Remove, TextRole>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
 
-    let current_font = using_font
-        .map(UsingFont::get)
-        .or(default_font.map(|font| font.0))
-        .ok_or(FontError::CannotFindFont { text: update.0 })?;
+fn type_scale_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+    font_handles: Populated<Entity, (With<FontCollection>, Changed<TypeScale>)>,
+) {
+    font_handles
+        .iter()
+        .flat_map(|collection| users.iter(collection))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
+}
 
-    let default_font_size = fonts
-        .get(current_font)
-        .map_err(|err| FontError::InvalidFont(current_font, err))?;
+// Classes
 
-    text_font.font_size = font_size
-        .map(FontSize::into_inner)
-        .unwrap_or(default_font_size.0);
+// Classes can set the collection as well as the weight, size and color, so they all need
+// updating.
 
-    Ok(())
+fn on_add_text_class(
+    on_add: On<Add, TextClass>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+fn changed_text_class(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, Changed<TextClass>>,
+) {
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
+}
+
+fn on_remove_text_class(
+    on_remove: On<Remove, TextClass>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.all(entity);
+    });
+}
+
+// Classes refer to collections by name, so they need updating when the registry changes as well.
+fn style_sheet_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    classes: Populated<Entity, With<TextClass>>,
+) {
+    classes
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.all(entity);
+        });
 }
 
 // Font Color
 
-fn on_add_font_color(on_add: On<Add, FontColor>, mut commands: Commands) {
-    commands.entity(on_add.entity).trigger(UpdateFontColor);
+// The shadow is updated along with the color.
+
+fn on_add_font_color(
+    on_add: On<Add, (FontColor, FontShadow, NoTextShadow)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_add.entity).for_each(|entity| {
+        dirty.color(entity);
+    });
 }
 
+#[allow(clippy::type_complexity)]
 fn changed_font_color(
-    mut commands: Commands,
-    changed: Populated<Entity, (With<ReactiveFont>, Changed<FontColor>)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+    changed: Populated<Entity, Or<(Changed<FontColor>, Changed<FontShadow>)>>,
 ) {
-    changed.iter().for_each(|entity| {
-        commands.entity(entity).trigger(UpdateFontColor);
+    changed
+        .iter()
+        .flat_map(|entity| inheritors.iter(entity))
+        .for_each(|entity| {
+            dirty.color(entity);
+        });
+}
+
+fn on_remove_font_color(
+    on_remove: On<Remove, (FontColor, FontShadow, NoTextShadow)>,
+    mut dirty: ResMut<DirtyFonts>,
+    inheritors: Inheritors,
+) {
+    inheritors.iter(on_remove.entity).for_each(|entity| {
+        dirty.color(entity);
+    });
+}
+
+fn on_remove_default_text_shadow(
+    on_remove: On<Remove, DefaultTextShadow>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+) {
+    users.iter(on_remove.entity).for_each(|entity| {
+        dirty.color(entity);
     });
 }
 
-fn on_remove_font_color(on_remove: On<Remove, FontColor>, mut commands: Commands) {
-    commands.entity(on_remove.entity).trigger(UpdateFontColor);
+fn update_font_color(update: On<UpdateFontColor>, mut dirty: ResMut<DirtyFonts>) {
+    dirty.color(update.0);
 }
 
-fn update_font_color(
-    update: On<UpdateFontColor>,
-    mut reactive_fonts: Query<(&mut TextColor, Option<&FontColor>, Option<&UsingFont>)>,
-    fonts: Query<&DefaultFontColor, With<FontCollection>>,
-    default_font: Option<Res<DefaultFont>>,
-) -> Result<(), BevyError> {
-    if let Err(QueryEntityError::EntityDoesNotExist(_)) = reactive_fonts.get_mut(update.0) {
-        // Happens when the entity has been despawned, ignore it.
-        return Ok(());
-    }
+fn font_theme_changed(mut dirty: ResMut<DirtyFonts>, fonts: Populated<Entity, With<ReactiveFont>>) {
+    fonts.iter().for_each(|entity| {
+        dirty.color(entity);
+    });
+}
+
+// Fallbacks
 
-    let (mut text_color, font_color, using_font) = reactive_fonts
-        .get_mut(update.0)
-        .map_err(|err| FontError::InvalidReactiveFont(update.0, err))?;
+// The text needs splitting again with the new fallbacks.
+fn changed_fallback_collections(
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+    changed: Populated<Entity, (With<FontCollection>, Changed<FallbackCollections>)>,
+) {
+    changed
+        .iter()
+        .flat_map(|collection| users.iter(collection))
+        .for_each(|entity| {
+            dirty.font(entity);
+        });
+}
 
-    let current_font = using_font
-        .map(UsingFont::get)
-        .or(default_font.map(|font| font.0))
-        .ok_or(FontError::CannotFindFont { text: update.0 })?;
+fn on_remove_fallback_collections(
+    on_remove: On<Remove, FallbackCollections>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+) {
+    users.iter(on_remove.entity).for_each(|entity| {
+        dirty.font(entity);
+    });
+}
 
-    let default_font_color = fonts
-        .get(current_font)
-        .map_err(|err| FontError::InvalidFont(current_font, err))?;
+// Glyphs can't be checked until a font has loaded, so text is split again once it has, whether
+// it's the text's own collection or one of its fallbacks.
+fn fallback_collection_loaded(
+    loaded: On<FontCollectionLoaded>,
+    mut dirty: ResMut<DirtyFonts>,
+    users: CollectionUsers,
+    fallbacks: Query<(Entity, &FallbackCollections)>,
+) {
+    fallbacks
+        .iter()
+        .filter(|(collection, fallbacks)| *collection == loaded.0 || fallbacks.contains(&loaded.0))
+        .flat_map(|(collection, _)| users.iter(collection))
+        .for_each(|entity| {
+            dirty.font(entity);
+        });
+}
 
-    text_color.0 = font_color
-        .map(FontColor::into_inner)
-        .unwrap_or(default_font_color.0);
+// Accessibility
 
-    Ok(())
+fn accessibility_settings_changed(
+    mut dirty: ResMut<DirtyFonts>,
+    fonts: Populated<Entity, With<ReactiveFont>>,
+) {
+    fonts.iter().for_each(|entity| {
+        dirty.all(entity);
+    });
 }
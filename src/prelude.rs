@@ -1,9 +1,27 @@
 pub use crate::{
-    error::FontError,
+    accessibility::AccessibilitySettings,
+    asset::{FontCollectionAsset, FontCollectionHandle, FontCollectionLoader},
+    class::{ClassStyle, TextClass, TextStyleSheet},
+    coverage::{GlyphCoverage, MissingGlyphs},
+    error::{ErrorPolicy, FontCollectionLoaderError, FontError, FontErrorPolicy, MarkupError},
+    fallback::{FallbackCollections, FallbackSpan, FallbackText},
     font::{
-        Bold, BoldFont, BoldItalicFont, DefaultFont, DefaultFontColor, DefaultFontSize,
-        FontCollection, FontColor, FontSize, Italic, ItalicFont, ReactiveFont, RegularFont, UsedBy,
-        UsingFont,
+        Bold, BoldFont, BoldItalicFont, CollectionFontScale, DefaultFont, DefaultFontColor,
+        DefaultFontScope, DefaultFontSize, DefaultFontSmoothing, DefaultLineHeight,
+        DefaultTextShadow, FallbackFont, FixedFontSize, FontCollection, FontColor, FontFace,
+        FontFaces, FontFallback, FontName, FontRegistry, FontScale, FontShadow, FontSize,
+        FontSizeClamp, FontSmoothing, FontVariant, FontWeight, Italic, ItalicFont, LineHeight,
+        NoTextShadow, ReactiveFont, RegularFont, UnresolvedFont, UseFontNamed, UsedBy, UsingFont,
     },
-    plugin::{ReactiveFontPlugin, UpdateFont, UpdateFontColor, UpdateFontSize},
+    load_state::{
+        FaceLoadState, FaceLoadStates, FontCollectionLoadState, FontCollectionLoaded,
+        all_font_collections_loaded,
+    },
+    locale::{FontLocale, LocaleFont},
+    markup::{Markup, MarkupSegment, MarkupSpan, UpdateMarkup, parse_markup},
+    plugin::{
+        ReactiveFontPlugin, ReactiveFontSystems, UpdateFont, UpdateFontColor, UpdateFontSize,
+    },
+    role::{RoleStyle, TextRole, TypeScale},
+    theme::{FontTheme, ThemePalette},
 };
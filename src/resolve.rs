@@ -0,0 +1,444 @@
+use crate::{
+    error::FontErrors,
+    face,
+    inherit::{Inherited, InheritedCollection, InheritedStyle},
+    prelude::*,
+};
+use bevy::{
+    ecs::{
+        component::Mutable,
+        entity::{Entities, EntityHashMap},
+        query::QueryItem,
+        system::SystemParam,
+    },
+    prelude::*,
+};
+#[cfg(feature = "text2d")]
+use bevy::{sprite::Text2dShadow, window::PrimaryWindow};
+
+/// The parts of a [`ReactiveFont`] that need updating.
+#[derive(Clone, Copy, Default, Debug)]
+pub(crate) struct DirtyParts {
+    pub font: bool,
+    pub size: bool,
+    pub color: bool,
+}
+
+/// The [`ReactiveFont`]'s that have changed since they were last resolved.
+///
+/// Changes only mark text here, it's all resolved at once by [`resolve_fonts`] so that text
+/// affected by several changes in the same frame is only updated once.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct DirtyFonts(EntityHashMap<DirtyParts>);
+impl DirtyFonts {
+    pub fn font(&mut self, entity: Entity) {
+        self.0.entry(entity).or_default().font = true;
+    }
+    pub fn size(&mut self, entity: Entity) {
+        self.0.entry(entity).or_default().size = true;
+    }
+    pub fn color(&mut self, entity: Entity) {
+        self.0.entry(entity).or_default().color = true;
+    }
+    /// The text that needs its font updating
+    pub fn fonts(&self) -> impl Iterator<Item = Entity> {
+        self.0
+            .iter()
+            .filter(|(_, parts)| parts.font)
+            .map(|(entity, _)| *entity)
+    }
+    pub fn all(&mut self, entity: Entity) {
+        self.0.insert(
+            entity,
+            DirtyParts {
+                font: true,
+                size: true,
+                color: true,
+            },
+        );
+    }
+}
+
+type CollectionQuery = (
+    &'static RegularFont,
+    &'static ItalicFont,
+    &'static BoldFont,
+    &'static BoldItalicFont,
+    &'static FontFaces,
+    &'static DefaultFontSize,
+    &'static DefaultFontColor,
+    &'static DefaultLineHeight,
+    &'static DefaultFontSmoothing,
+    Option<&'static DefaultTextShadow>,
+    Option<&'static CollectionFontScale>,
+    &'static TypeScale,
+);
+
+type CollectionFaces<'a> = (
+    &'a RegularFont,
+    &'a ItalicFont,
+    &'a BoldFont,
+    &'a BoldItalicFont,
+    &'a FontFaces,
+);
+
+/// Everything needed to work out how a peice of text should look.
+#[derive(SystemParam)]
+pub(crate) struct FontResolver<'w, 's> {
+    style: InheritedStyle<'w, 's>,
+    collection: InheritedCollection<'w, 's>,
+    viewports: Inherited<'w, 's, ComputedUiRenderTargetInfo>,
+    #[cfg(feature = "text2d")]
+    primary_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    fixed_size: Inherited<'w, 's, FixedFontSize>,
+    font_scale: Res<'w, FontScale>,
+    accessibility: Res<'w, AccessibilitySettings>,
+    fonts: Query<'w, 's, CollectionQuery, With<FontCollection>>,
+    fallback_spans: Query<'w, 's, &'static FallbackSpan>,
+}
+impl FontResolver<'_, '_> {
+    /// The logical size of the viewport the text is rendered to.
+    fn viewport(&self, text: Entity) -> Vec2 {
+        if let Some(target) = self.viewports.get(text) {
+            return target.logical_size();
+        }
+
+        // `Text2d` isn't rendered by the UI, so it follows the size of the window instead.
+        #[cfg(feature = "text2d")]
+        if let Ok(window) = self.primary_window.single() {
+            return window.size();
+        }
+
+        Vec2::ZERO
+    }
+
+    /// The faces of the collection a [`FallbackSpan`] is using.
+    fn fallback_faces(&self, text: Entity) -> Option<CollectionFaces<'_>> {
+        let collection = self.fallback_spans.get(text).ok()?.0?;
+        let (regular, italic, bold, bold_italic, faces, ..) = self.fonts.get(collection).ok()?;
+        Some((regular, italic, bold, bold_italic, faces))
+    }
+
+    /// The [`FontCollection`] the text is using.
+    fn collection(&self, text: Entity) -> Result<QueryItem<'_, '_, CollectionQuery>, FontError> {
+        let current_font = self
+            .collection
+            .get(text)
+            .ok_or(FontError::CannotFindFont { text })?;
+
+        self.fonts
+            .get(current_font)
+            .map_err(|err| FontError::InvalidFont(current_font, err))
+    }
+}
+
+/// Marks a shadow that was added by the plugin, so one added by hand isn't removed when the text
+/// doesn't have a shadow of its own.
+#[derive(Component, Default)]
+pub(crate) struct ManagedTextShadow;
+
+/// Sets the shadow of the text, only the root text has one so spans are left alone.
+#[derive(SystemParam)]
+pub(crate) struct TextShadows<'w, 's> {
+    ui: Query<'w, 's, (Option<&'static mut TextShadow>, Has<ManagedTextShadow>), With<Text>>,
+    #[cfg(feature = "text2d")]
+    world: Query<'w, 's, (Option<&'static mut Text2dShadow>, Has<ManagedTextShadow>), With<Text2d>>,
+}
+impl TextShadows<'_, '_> {
+    fn set(&mut self, commands: &mut Commands, text: Entity, shadow: Option<TextShadow>) {
+        if let Ok((text_shadow, managed)) = self.ui.get_mut(text) {
+            set_shadow(commands, text, shadow, text_shadow, managed);
+        }
+
+        #[cfg(feature = "text2d")]
+        if let Ok((text_shadow, managed)) = self.world.get_mut(text) {
+            // `Text2d` is drawn with y pointing up, unlike the UI.
+            let shadow = shadow.map(|shadow| Text2dShadow {
+                offset: shadow.offset * Vec2::new(1., -1.),
+                color: shadow.color,
+            });
+            set_shadow(commands, text, shadow, text_shadow, managed);
+        }
+    }
+}
+
+fn set_shadow<S: Component<Mutability = Mutable> + PartialEq>(
+    commands: &mut Commands,
+    text: Entity,
+    shadow: Option<S>,
+    text_shadow: Option<Mut<S>>,
+    managed: bool,
+) {
+    match (shadow, text_shadow) {
+        (Some(shadow), Some(mut text_shadow)) => {
+            text_shadow.set_if_neq(shadow);
+            if !managed {
+                commands.entity(text).try_insert(ManagedTextShadow);
+            }
+        }
+        (Some(shadow), None) => {
+            commands
+                .entity(text)
+                .try_insert((shadow, ManagedTextShadow));
+        }
+        (None, _) if managed => {
+            commands.entity(text).try_remove::<(S, ManagedTextShadow)>();
+        }
+        (None, _) => {}
+    }
+}
+
+/// Updates every [`ReactiveFont`] in [`DirtyFonts`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn resolve_fonts(
+    mut commands: Commands,
+    mut dirty: ResMut<DirtyFonts>,
+    entities: &Entities,
+    mut text_fonts: Query<(&mut TextFont, Option<&FontFallback>)>,
+    mut text_colors: Query<&mut TextColor>,
+    mut text_shadows: TextShadows,
+    resolver: FontResolver,
+    mut errors: FontErrors,
+) -> Result<(), BevyError> {
+    if dirty.0.is_empty() {
+        return Ok(());
+    }
+
+    let mut propagated = None;
+
+    core::mem::take(&mut dirty.0)
+        .into_iter()
+        .for_each(|(text, parts)| {
+            if !entities.contains(text) {
+                // Happens when the entity has been despawned, ignore it.
+                return;
+            }
+
+            let result = resolve_font(
+                &mut commands,
+                text,
+                parts,
+                &mut text_fonts,
+                &mut text_colors,
+                &mut text_shadows,
+                &resolver,
+            );
+
+            if let Err(error) = errors.handle(text, result) {
+                propagated.get_or_insert(error);
+            }
+        });
+
+    // Only the first error makes it to bevy's error handler, the rest of the text still gets
+    // updated.
+    propagated.map_or(Ok(()), Err)
+}
+
+fn resolve_font(
+    commands: &mut Commands,
+    text: Entity,
+    parts: DirtyParts,
+    text_fonts: &mut Query<(&mut TextFont, Option<&FontFallback>)>,
+    text_colors: &mut Query<&mut TextColor>,
+    text_shadows: &mut TextShadows,
+    resolver: &FontResolver,
+) -> Result<(), FontError> {
+    let (
+        regular_font,
+        italic_font,
+        bold_font,
+        bold_italic_font,
+        font_faces,
+        default_size,
+        default_color,
+        default_line_height,
+        default_smoothing,
+        default_shadow,
+        collection_scale,
+        type_scale,
+    ) = resolver.collection(text)?;
+
+    if parts.font || parts.size {
+        let (mut text_font, font_fallback) = text_fonts
+            .get_mut(text)
+            .map_err(|err| FontError::InvalidReactiveFont(text, err))?;
+
+        if parts.font {
+            let (weight, is_italic) = resolver.style.weight(text, type_scale);
+            let is_italic = is_italic && !resolver.accessibility.disable_italics;
+
+            // Fallback spans use the faces of the collection that has glyphs for them.
+            let (regular_font, italic_font, bold_font, bold_italic_font, font_faces) =
+                resolver.fallback_faces(text).unwrap_or((
+                    regular_font,
+                    italic_font,
+                    bold_font,
+                    bold_italic_font,
+                    font_faces,
+                ));
+
+            let faces = face::collection_faces(
+                regular_font,
+                italic_font,
+                bold_font,
+                bold_italic_font,
+                font_faces,
+            );
+
+            let requested = FontVariant::new(weight.is_bold(), is_italic);
+            // Nothing was set at all, let it use bevy's default font.
+            let (font, used) = face::resolve_face(faces, weight, is_italic)
+                .unwrap_or((&regular_font.0, FontVariant::Regular));
+
+            // Only touch the text when something changed, so it doesn't get laid out again.
+            if text_font.font != *font {
+                text_font.font = font.clone();
+            }
+
+            match (used != requested, font_fallback) {
+                (true, _) => {
+                    commands
+                        .entity(text)
+                        .try_insert(FontFallback { requested, used });
+                }
+                (false, Some(_)) => {
+                    commands.entity(text).try_remove::<FontFallback>();
+                }
+                (false, None) => {}
+            }
+        }
+
+        if parts.size {
+            let viewport = resolver.viewport(text);
+            let font_size = resolver
+                .style
+                .size(text, type_scale, default_size.0, viewport);
+
+            let scale = match resolver.fixed_size.get(text) {
+                Some(_) => 1.,
+                None => collection_scale.map_or(resolver.font_scale.0, |scale| scale.0),
+            };
+            let font_size = resolver
+                .accessibility
+                .min_font_size
+                .map_or(font_size * scale, |min| (font_size * scale).max(min));
+
+            if text_font.font_size != font_size {
+                text_font.font_size = font_size;
+            }
+
+            // The line height and smoothing are updated along with the size.
+            let line_height = resolver
+                .style
+                .line_height(text, type_scale)
+                .unwrap_or(default_line_height.0)
+                .into();
+            if text_font.line_height != line_height {
+                text_font.line_height = line_height;
+            }
+
+            let smoothing = resolver
+                .style
+                .smoothing(text, type_scale)
+                .unwrap_or(default_smoothing.0)
+                .into();
+            if text_font.font_smoothing != smoothing {
+                text_font.font_smoothing = smoothing;
+            }
+        }
+    }
+
+    if parts.color {
+        let mut text_color = text_colors
+            .get_mut(text)
+            .map_err(|err| FontError::InvalidReactiveFont(text, err))?;
+
+        let font_color = resolver
+            .accessibility
+            .high_contrast
+            .or_else(|| resolver.style.color(text, type_scale, default_color))
+            .unwrap_or_default();
+
+        text_color.set_if_neq(TextColor(font_color));
+
+        // The shadow is updated along with the color.
+        let shadow = resolver
+            .style
+            .shadow(text, default_shadow.map(DefaultTextShadow::into_inner));
+        text_shadows.set(commands, text, shadow);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::ReactiveFontPlugin;
+    use bevy::asset::uuid::Uuid;
+
+    fn font(id: u128) -> Handle<Font> {
+        Handle::Uuid(Uuid::from_u128(id), Default::default())
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        // Keep going after an error is propagated, so the text around it can be checked.
+        app.set_error_handler(bevy::ecs::error::ignore);
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ReactiveFontPlugin::default(),
+        ));
+        let collection = app
+            .world_mut()
+            .spawn((FontCollection, RegularFont(font(1)), DefaultFontSize(20.)))
+            .id();
+        app.insert_resource(DefaultFont(collection));
+        app
+    }
+
+    #[test]
+    fn changes_in_the_same_frame_are_resolved_together() {
+        let mut app = app();
+        let collection = app.world().resource::<DefaultFont>().0;
+        let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(text)
+            .insert((FontSize::Px(30.), FontColor::new(Color::BLACK)));
+        app.world_mut()
+            .entity_mut(collection)
+            .insert(DefaultFontSize(10.));
+        app.world_mut().run_schedule(Update);
+
+        let dirty = &app.world().resource::<DirtyFonts>().0;
+        assert_eq!(dirty.len(), 1);
+        let parts = dirty[&text];
+        assert!(parts.size && parts.color);
+
+        app.update();
+        assert!(app.world().resource::<DirtyFonts>().0.is_empty());
+        assert_eq!(app.world().get::<TextFont>(text).unwrap().font_size, 30.);
+        assert_eq!(app.world().get::<TextColor>(text).unwrap().0, Color::BLACK);
+    }
+
+    #[test]
+    fn failing_text_doesnt_stop_the_rest() {
+        let mut app = app();
+        let not_a_collection = app.world_mut().spawn_empty().id();
+        let texts = [(); 3].map(|_| app.world_mut().spawn((Text::default(), ReactiveFont)).id());
+        let broken = app
+            .world_mut()
+            .spawn((Text::default(), ReactiveFont, UsingFont(not_a_collection)))
+            .id();
+        app.update();
+
+        assert!(app.world().get::<UnresolvedFont>(broken).is_some());
+        for text in texts {
+            assert!(app.world().get::<UnresolvedFont>(text).is_none());
+            assert_eq!(app.world().get::<TextFont>(text).unwrap().font_size, 20.);
+        }
+    }
+}
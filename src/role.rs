@@ -0,0 +1,150 @@
+use crate::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// What a peice of text is for, its size, weight, style and color come from the [`TypeScale`] of
+/// the [`FontCollection`] it's using.
+///
+/// Anything set directly on the text, such as [`FontSize`] or [`Bold`], takes priority over the
+/// role. When placed on a container every [`ReactiveFont`] below it will inherit the role's size
+/// and color, and any [`TextSpan`]'s will inherit its weight and style.
+#[derive(Component, Reflect, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum TextRole {
+    Heading1,
+    Heading2,
+    Heading3,
+    Heading4,
+    Heading5,
+    Heading6,
+    Body,
+    Caption,
+    Code,
+    Label,
+}
+
+/// How text with a [`TextRole`] should look. Anything left as [`None`] is inherited as normal.
+#[derive(Reflect, PartialEq, Clone, Default, Debug)]
+pub struct RoleStyle {
+    pub size: Option<FontSize>,
+    pub weight: Option<FontWeight>,
+    pub italic: Option<bool>,
+    pub color: Option<FontColor>,
+}
+impl RoleStyle {
+    /// Creates a new [`RoleStyle`] that doesn't change anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the size
+    pub fn with_size(mut self, size: impl Into<FontSize>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+    /// Sets the weight
+    pub fn with_weight(mut self, weight: impl Into<FontWeight>) -> Self {
+        self.weight = Some(weight.into());
+        self
+    }
+    /// Sets whether the text is italic
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = Some(italic);
+        self
+    }
+    /// Sets the color
+    pub fn with_color(mut self, color: impl Into<FontColor>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The style of each [`TextRole`] for a [`FontCollection`].
+///
+/// By default headings are bold and sized relative to [`DefaultFontSize`], so changing it will
+/// resize them as well.
+#[derive(Component, Reflect, PartialEq, Clone, Debug)]
+pub struct TypeScale(HashMap<TextRole, RoleStyle>);
+impl Default for TypeScale {
+    fn default() -> Self {
+        Self::empty()
+            .with_role(
+                TextRole::Heading1,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(2.))
+                    .with_weight(FontWeight::BOLD),
+            )
+            .with_role(
+                TextRole::Heading2,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(1.5))
+                    .with_weight(FontWeight::BOLD),
+            )
+            .with_role(
+                TextRole::Heading3,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(1.25))
+                    .with_weight(FontWeight::BOLD),
+            )
+            .with_role(
+                TextRole::Heading4,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(1.1))
+                    .with_weight(FontWeight::SEMI_BOLD),
+            )
+            .with_role(
+                TextRole::Heading5,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(1.))
+                    .with_weight(FontWeight::SEMI_BOLD),
+            )
+            .with_role(
+                TextRole::Heading6,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(0.875))
+                    .with_weight(FontWeight::SEMI_BOLD),
+            )
+            .with_role(
+                TextRole::Body,
+                RoleStyle::new().with_size(FontSize::Rem(1.)),
+            )
+            .with_role(
+                TextRole::Caption,
+                RoleStyle::new().with_size(FontSize::Rem(0.8)),
+            )
+            .with_role(
+                TextRole::Code,
+                RoleStyle::new().with_size(FontSize::Rem(1.)),
+            )
+            .with_role(
+                TextRole::Label,
+                RoleStyle::new()
+                    .with_size(FontSize::Rem(0.9))
+                    .with_weight(FontWeight::MEDIUM),
+            )
+    }
+}
+impl TypeScale {
+    /// Creates a [`TypeScale`] where no role changes anything
+    pub fn empty() -> Self {
+        Self(HashMap::default())
+    }
+    /// Sets the style of a role
+    pub fn with_role(mut self, role: TextRole, style: RoleStyle) -> Self {
+        self.0.insert(role, style);
+        self
+    }
+    /// The style of a role
+    pub fn get(&self, role: TextRole) -> Option<&RoleStyle> {
+        self.0.get(&role)
+    }
+    /// Sets the style of a role, returning the previous style
+    pub fn insert(&mut self, role: TextRole, style: RoleStyle) -> Option<RoleStyle> {
+        self.0.insert(role, style)
+    }
+    /// Removes the style of a role, returning it
+    pub fn remove(&mut self, role: TextRole) -> Option<RoleStyle> {
+        self.0.remove(&role)
+    }
+    /// Iterates over each role and its style
+    pub fn iter(&self) -> impl Iterator<Item = (TextRole, &RoleStyle)> {
+        self.0.iter().map(|(role, style)| (*role, style))
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// The colors of a theme, by their name such as `"primary"`, `"muted"` or `"danger"`.
+#[derive(Reflect, PartialEq, Clone, Default, Debug)]
+pub struct ThemePalette(HashMap<String, Color>);
+impl ThemePalette {
+    /// Creates a new [`ThemePalette`] without any colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds a color
+    pub fn with_color(mut self, name: impl Into<String>, color: impl Into<Color>) -> Self {
+        self.0.insert(name.into(), color.into());
+        self
+    }
+    /// Finds a color by its name
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.0.get(name).copied()
+    }
+    /// Sets a color, returning the previous one
+    pub fn insert(&mut self, name: impl Into<String>, color: impl Into<Color>) -> Option<Color> {
+        self.0.insert(name.into(), color.into())
+    }
+    /// Removes a color, returning it
+    pub fn remove(&mut self, name: &str) -> Option<Color> {
+        self.0.remove(name)
+    }
+    /// Iterates over each name and its color
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.0.iter().map(|(name, color)| (name.as_str(), *color))
+    }
+}
+
+/// Named themes, such as `"light"`, `"dark"` and `"high_contrast"`, that [`FontColor::Theme`]
+/// and [`DefaultFontColor`] look their colors up in.
+///
+/// Switching the active theme, or changing any of its colors, will recolor every [`ReactiveFont`].
+///
+/// [`FontColor::Theme`]: crate::font::FontColor::Theme
+/// [`DefaultFontColor`]: crate::font::DefaultFontColor
+/// [`ReactiveFont`]: crate::font::ReactiveFont
+#[derive(Resource, Reflect, Clone, Default, Debug)]
+pub struct FontTheme {
+    active: String,
+    themes: HashMap<String, ThemePalette>,
+}
+impl FontTheme {
+    /// Creates a new [`FontTheme`] without any themes, where `active` will be used once it's added
+    pub fn new(active: impl Into<String>) -> Self {
+        Self {
+            active: active.into(),
+            themes: HashMap::default(),
+        }
+    }
+    /// Adds a theme
+    pub fn with_theme(mut self, name: impl Into<String>, palette: ThemePalette) -> Self {
+        self.themes.insert(name.into(), palette);
+        self
+    }
+    /// The name of the active theme
+    pub fn active(&self) -> &str {
+        &self.active
+    }
+    /// Switches to another theme
+    pub fn set_active(&mut self, name: impl Into<String>) {
+        self.active = name.into();
+    }
+    /// The palette of the active theme, if it exists
+    pub fn palette(&self) -> Option<&ThemePalette> {
+        self.themes.get(&self.active)
+    }
+    /// Finds a color in the active theme by its name
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.palette().and_then(|palette| palette.get(name))
+    }
+    /// The palette of a theme
+    pub fn theme(&self, name: &str) -> Option<&ThemePalette> {
+        self.themes.get(name)
+    }
+    /// The palette of a theme, so its colors can be changed
+    pub fn theme_mut(&mut self, name: &str) -> Option<&mut ThemePalette> {
+        self.themes.get_mut(name)
+    }
+    /// Sets the palette of a theme, returning the previous palette
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        palette: ThemePalette,
+    ) -> Option<ThemePalette> {
+        self.themes.insert(name.into(), palette)
+    }
+    /// Removes a theme, returning its palette
+    pub fn remove(&mut self, name: &str) -> Option<ThemePalette> {
+        self.themes.remove(name)
+    }
+    /// Iterates over each theme and its palette
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ThemePalette)> {
+        self.themes
+            .iter()
+            .map(|(name, palette)| (name.as_str(), palette))
+    }
+}
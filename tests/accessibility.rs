@@ -0,0 +1,119 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+fn color_of(app: &App, entity: Entity) -> Color {
+    app.world().get::<TextColor>(entity).unwrap().0
+}
+
+fn settings(app: &mut App) -> Mut<'_, AccessibilitySettings> {
+    app.world_mut().resource_mut::<AccessibilitySettings>()
+}
+
+#[test]
+fn dyslexic_font_replaces_every_collection() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    let dyslexic = spawn_collection(&mut app, 3);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    let own_font = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, UsingFont(other)))
+        .id();
+    app.update();
+
+    settings(&mut app).dyslexic_font = Some(dyslexic);
+    app.update();
+    assert_eq!(font_of(&app, text), font(3).id());
+    assert_eq!(font_of(&app, own_font), font(3).id());
+
+    settings(&mut app).dyslexic_font = None;
+    app.update();
+    assert_eq!(font_of(&app, text), font(1).id());
+    assert_eq!(font_of(&app, own_font), font(2).id());
+}
+
+#[test]
+fn high_contrast_replaces_every_color() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontColor::new(Color::BLACK)))
+        .id();
+    app.update();
+
+    settings(&mut app).high_contrast = Some(Srgba::RED.into());
+    app.update();
+    assert_eq!(color_of(&app, text), Srgba::RED.into());
+
+    settings(&mut app).high_contrast = None;
+    app.update();
+    assert_eq!(color_of(&app, text), Color::BLACK);
+}
+
+#[test]
+fn min_font_size_applies_after_scaling() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FontScale(0.5));
+
+    let small = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontSize::Px(20.)))
+        .id();
+    let large = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontSize::Px(40.)))
+        .id();
+    app.update();
+    assert_eq!(size_of(&app, small), 10.);
+
+    settings(&mut app).min_font_size = Some(16.);
+    app.update();
+    assert_eq!(size_of(&app, small), 16.);
+    assert_eq!(size_of(&app, large), 20.);
+
+    settings(&mut app).min_font_size = None;
+    app.update();
+    assert_eq!(size_of(&app, small), 10.);
+}
+
+#[test]
+fn italics_can_be_disabled() {
+    let mut app = app();
+    let default = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(font(1)), ItalicFont(font(2))))
+        .id();
+    app.insert_resource(DefaultFont(default));
+
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, Italic))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    settings(&mut app).disable_italics = true;
+    app.update();
+    assert_eq!(font_of(&app, text), font(1).id());
+    // Asking for italics isn't a missing face, so there's no fallback to report.
+    assert!(app.world().get::<FontFallback>(text).is_none());
+
+    settings(&mut app).disable_italics = false;
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font};
+use std::time::Duration;
+
+fn asset(name: Option<&str>) -> FontCollectionAsset {
+    FontCollectionAsset {
+        name: name.map(str::to_string),
+        regular: RegularFont(font(1)),
+        italic: ItalicFont::default(),
+        bold: BoldFont::default(),
+        bold_italic: BoldItalicFont::default(),
+        faces: FontFaces::default(),
+        default_size: None,
+        default_color: None,
+    }
+}
+
+#[test]
+fn collection_is_loaded_from_manifest() {
+    let mut app = app();
+    // The fonts in the file are loaded along with it.
+    app.init_asset::<Font>();
+    let handle = app
+        .world()
+        .resource::<AssetServer>()
+        .load("fonts/liberation_mono.fontcollection.ron");
+    let collection = app.world_mut().spawn(FontCollectionHandle(handle)).id();
+
+    // Loading happens on another thread, so give it a moment.
+    for _ in 0..200 {
+        app.update();
+        if app.world().get::<FontName>(collection).is_some() {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let world = app.world();
+    assert_eq!(
+        world.get::<FontName>(collection),
+        Some(&FontName::new("liberation_mono"))
+    );
+    assert_eq!(world.get::<DefaultFontSize>(collection).unwrap().0, 20.);
+    assert_eq!(
+        world.get::<DefaultFontColor>(collection),
+        Some(&DefaultFontColor::new(Srgba::hex("#bf85d1").unwrap()))
+    );
+
+    let server = world.resource::<AssetServer>();
+    assert_eq!(
+        world.get::<RegularFont>(collection).unwrap().0,
+        server
+            .get_handle("fonts/liberation_mono/regular.ttf")
+            .unwrap()
+    );
+    assert_eq!(
+        world.get::<BoldItalicFont>(collection).unwrap().0,
+        server
+            .get_handle("fonts/liberation_mono/bold_italic.ttf")
+            .unwrap()
+    );
+}
+
+#[test]
+fn reloading_follows_the_file() {
+    let mut app = app();
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<FontCollectionAsset>>()
+        .add(FontCollectionAsset {
+            default_size: Some(DefaultFontSize(30.)),
+            default_color: Some(DefaultFontColor::new(Color::BLACK)),
+            ..asset(Some("body"))
+        });
+    let collection = app
+        .world_mut()
+        .spawn(FontCollectionHandle(handle.clone()))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<FontName>(collection),
+        Some(&FontName::new("body"))
+    );
+    assert_eq!(
+        app.world().resource::<FontRegistry>().get("body"),
+        Some(collection)
+    );
+    assert_eq!(
+        app.world().get::<DefaultFontSize>(collection).unwrap().0,
+        30.
+    );
+
+    // The name and defaults are taken out of the file.
+    *app.world_mut()
+        .resource_mut::<Assets<FontCollectionAsset>>()
+        .get_mut(&handle)
+        .unwrap() = asset(None);
+    app.update();
+
+    assert!(app.world().get::<FontName>(collection).is_none());
+    assert!(app.world().resource::<FontRegistry>().get("body").is_none());
+    assert_eq!(
+        app.world().get::<DefaultFontSize>(collection),
+        Some(&DefaultFontSize::default())
+    );
+    assert_eq!(
+        app.world().get::<DefaultFontColor>(collection),
+        Some(&DefaultFontColor::default())
+    );
+}
+
+#[test]
+fn fields_set_by_hand_are_kept() {
+    let mut app = app();
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<FontCollectionAsset>>()
+        .add(asset(None));
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollectionHandle(handle.clone()),
+            FontName::new("body"),
+            DefaultFontSize(30.),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<FontName>(collection),
+        Some(&FontName::new("body"))
+    );
+    assert_eq!(
+        app.world().get::<DefaultFontSize>(collection).unwrap().0,
+        30.
+    );
+
+    // Reloading a file that still doesn't set them leaves them alone as well.
+    *app.world_mut()
+        .resource_mut::<Assets<FontCollectionAsset>>()
+        .get_mut(&handle)
+        .unwrap() = asset(None);
+    app.update();
+
+    assert_eq!(
+        app.world().get::<FontName>(collection),
+        Some(&FontName::new("body"))
+    );
+    assert_eq!(
+        app.world().get::<DefaultFontSize>(collection).unwrap().0,
+        30.
+    );
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+fn color_of(app: &App, entity: Entity) -> Color {
+    app.world().get::<TextColor>(entity).unwrap().0
+}
+
+#[test]
+fn later_classes_override_earlier_ones() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(
+        TextStyleSheet::default()
+            .with_class(
+                "title",
+                ClassStyle::new()
+                    .with_size(FontSize::Px(30.))
+                    .with_color(Color::BLACK),
+            )
+            .with_class("small", ClassStyle::new().with_size(FontSize::Px(10.))),
+    );
+
+    let title = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, TextClass::new("title")))
+        .id();
+    let small_title = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            TextClass::new("title").with("small"),
+        ))
+        .id();
+    let title_small = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            TextClass::new("small").with("title"),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(size_of(&app, title), 30.);
+    assert_eq!(size_of(&app, small_title), 10.);
+    assert_eq!(color_of(&app, small_title), Color::BLACK);
+    assert_eq!(size_of(&app, title_small), 30.);
+}
+
+#[test]
+fn classes_pick_collections_by_name() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    app.world_mut()
+        .spawn((FontCollection, RegularFont(font(2)), FontName::new("mono")));
+    app.insert_resource(
+        TextStyleSheet::default().with_class("code", ClassStyle::new().with_font("mono")),
+    );
+
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, TextClass::new("code")))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    // A collection registered under the name later is picked up.
+    app.world_mut()
+        .spawn((FontCollection, RegularFont(font(3)), FontName::new("mono")));
+    app.update();
+    assert_eq!(font_of(&app, text), font(3).id());
+}
+
+#[test]
+fn editing_the_style_sheet_restyles_text() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(
+        TextStyleSheet::default().with_class("title", ClassStyle::new().with_size(30.)),
+    );
+
+    let container = app
+        .world_mut()
+        .spawn((Node::default(), TextClass::new("title")))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, ChildOf(container)))
+        .id();
+    app.update();
+    assert_eq!(size_of(&app, text), 30.);
+
+    app.world_mut().resource_mut::<TextStyleSheet>().insert(
+        "title",
+        ClassStyle::new().with_size(40.).with_color(Color::BLACK),
+    );
+    app.update();
+
+    assert_eq!(size_of(&app, text), 40.);
+    assert_eq!(color_of(&app, text), Color::BLACK);
+
+    app.world_mut()
+        .resource_mut::<TextStyleSheet>()
+        .remove("title");
+    app.update();
+
+    assert_eq!(size_of(&app, text), DefaultFontSize::default().0);
+}
@@ -0,0 +1,42 @@
+//! Fixtures shared by the tests and benches.
+// Not every test uses every fixture.
+#![allow(dead_code)]
+
+use bevy::{asset::uuid::Uuid, prelude::*};
+use bevy_reactive_font::prelude::*;
+
+/// A font handle that doesn't need loading, each `id` is a different font.
+pub fn font(id: u128) -> Handle<Font> {
+    Handle::Uuid(Uuid::from_u128(id), Default::default())
+}
+
+/// An app with the [`ReactiveFontPlugin`] and nothing else that isn't needed.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ReactiveFontPlugin::default(),
+    ));
+    app
+}
+
+/// Adds a font from one of the files in `assets/fonts`, so its glyphs can be checked.
+///
+/// The app needs `Assets<Font>`, which isn't added by [`app`].
+pub fn add_font(app: &mut App, data: &[u8]) -> Handle<Font> {
+    let font = Font::try_from_bytes(data.to_vec()).unwrap();
+    app.world_mut().resource_mut::<Assets<Font>>().add(font)
+}
+
+/// Spawns a collection that only has a regular face.
+pub fn spawn_collection(app: &mut App, id: u128) -> Entity {
+    app.world_mut()
+        .spawn((FontCollection, RegularFont(font(id))))
+        .id()
+}
+
+/// The font the text ended up using.
+pub fn font_of(app: &App, entity: Entity) -> AssetId<Font> {
+    app.world().get::<TextFont>(entity).unwrap().font.id()
+}
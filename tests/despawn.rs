@@ -0,0 +1,240 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+#[test]
+fn users_fall_back_to_default_font() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UsingFont(other)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    app.world_mut().despawn(other);
+    app.update();
+
+    assert!(app.world().get::<UsingFont>(text).is_none());
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn users_are_moved_to_fallback_font() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let fallback = spawn_collection(&mut app, 2);
+    let other = spawn_collection(&mut app, 3);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FallbackFont(fallback));
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UsingFont(other)))
+        .id();
+    app.update();
+
+    app.world_mut().despawn(other);
+    app.update();
+
+    assert_eq!(
+        app.world().get::<UsingFont>(text).map(|using| using.0),
+        Some(fallback)
+    );
+    assert_eq!(font_of(&app, text), font(2).id());
+}
+
+#[test]
+fn default_font_is_removed_without_fallback() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ReactiveFontPlugin {
+            error_policy: FontErrorPolicy::all(ErrorPolicy::Ignore),
+            ..Default::default()
+        },
+    ));
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+    assert!(app.world().get::<UnresolvedFont>(text).is_none());
+
+    app.world_mut().despawn(default);
+    app.update();
+
+    assert!(app.world().get_resource::<DefaultFont>().is_none());
+    assert!(app.world().get::<UnresolvedFont>(text).is_some());
+}
+
+#[test]
+fn default_font_is_replaced_by_fallback() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let fallback = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FallbackFont(fallback));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(1).id());
+
+    app.world_mut().despawn(default);
+    app.update();
+
+    assert_eq!(app.world().resource::<DefaultFont>().0, fallback);
+    assert_eq!(font_of(&app, text), font(2).id());
+}
+
+#[test]
+fn fallback_font_is_removed_when_despawned() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let fallback = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FallbackFont(fallback));
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UsingFont(fallback)))
+        .id();
+    app.update();
+
+    app.world_mut().despawn(fallback);
+    app.update();
+
+    assert!(app.world().get_resource::<FallbackFont>().is_none());
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn inheriting_users_fall_back() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let container = app
+        .world_mut()
+        .spawn((Node::default(), UsingFont(other)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), ChildOf(container)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    app.world_mut().despawn(other);
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn removing_font_collection_moves_users() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UsingFont(other)))
+        .id();
+    app.update();
+
+    app.world_mut().entity_mut(other).remove::<FontCollection>();
+    app.update();
+
+    assert!(app.world().get::<UsingFont>(text).is_none());
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn despawning_text_is_safe() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UsingFont(default)))
+        .id();
+    app.update();
+
+    app.world_mut().despawn(text);
+    app.update();
+
+    assert!(
+        app.world()
+            .get::<UsedBy>(default)
+            .is_none_or(|used_by| !used_by.iter().any(|user| user == text))
+    );
+}
+
+#[test]
+fn scoped_users_fall_back() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let scoped = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let container = app
+        .world_mut()
+        .spawn((Node::default(), DefaultFontScope(scoped)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), ChildOf(container)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    app.world_mut().despawn(scoped);
+    app.update();
+
+    assert!(app.world().get::<DefaultFontScope>(container).is_none());
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn named_users_move_to_remaining_collection() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let older = app
+        .world_mut()
+        .spawn((FontCollection, FontName::new("body"), RegularFont(font(2))))
+        .id();
+    let newer = app
+        .world_mut()
+        .spawn((FontCollection, FontName::new("body"), RegularFont(font(3))))
+        .id();
+
+    let text = app
+        .world_mut()
+        .spawn(((Text::default(), ReactiveFont), UseFontNamed::new("body")))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(3).id());
+
+    app.world_mut().despawn(newer);
+    app.update();
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<FontRegistry>().get("body"),
+        Some(older)
+    );
+    assert_eq!(font_of(&app, text), font(2).id());
+}
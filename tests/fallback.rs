@@ -0,0 +1,161 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{add_font, app};
+
+/// OpenDyslexic doesn't have Greek, but Liberation Mono does.
+fn spawn_collections(app: &mut App) -> (Entity, Entity) {
+    app.init_asset::<Font>();
+    let dyslexic = add_font(
+        app,
+        include_bytes!("../assets/fonts/opendyslexic/regular.otf"),
+    );
+    let mono = add_font(
+        app,
+        include_bytes!("../assets/fonts/liberation_mono/regular.ttf"),
+    );
+
+    let fallback = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(mono)))
+        .id();
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(dyslexic),
+            FallbackCollections(vec![fallback]),
+        ))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+
+    (collection, fallback)
+}
+
+/// The spans the text was split into, and the collection each one uses.
+fn spans(app: &App, text: Entity) -> Vec<(Entity, String, Option<Entity>)> {
+    let world = app.world();
+    world
+        .get::<Children>(text)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| {
+            let span = world.get::<FallbackSpan>(*child)?;
+            let content = world.get::<TextSpan>(*child)?;
+            Some((*child, content.0.clone(), span.0))
+        })
+        .collect()
+}
+
+fn runs(spans: &[(Entity, String, Option<Entity>)]) -> Vec<(&str, Option<Entity>)> {
+    spans
+        .iter()
+        .map(|(_, run, collection)| (run.as_str(), *collection))
+        .collect()
+}
+
+#[test]
+fn text_is_split_resplit_and_restored() {
+    let mut app = app();
+    let (collection, fallback) = spawn_collections(&mut app);
+
+    let text = app
+        .world_mut()
+        .spawn((Text::new("Hi Ωμέγα"), ReactiveFont))
+        .id();
+    app.update();
+
+    let split = spans(&app, text);
+    assert_eq!(runs(&split), [("Hi ", None), ("Ωμέγα", Some(fallback))]);
+    assert_eq!(app.world().get::<Text>(text).unwrap().0, "");
+    assert_eq!(app.world().get::<FallbackText>(text).unwrap().0, "Hi Ωμέγα");
+    assert_eq!(
+        app.world().get::<TextFont>(split[1].0).unwrap().font,
+        app.world().get::<RegularFont>(fallback).unwrap().0
+    );
+
+    // Setting the text splits it again.
+    app.world_mut().get_mut::<Text>(text).unwrap().0 = "Ω ok".to_string();
+    app.update();
+
+    assert_eq!(
+        runs(&spans(&app, text)),
+        [("Ω ", Some(fallback)), ("ok", None)]
+    );
+    assert_eq!(app.world().get::<FallbackText>(text).unwrap().0, "Ω ok");
+
+    // Without fallbacks the text gets its content back.
+    app.world_mut()
+        .entity_mut(collection)
+        .remove::<FallbackCollections>();
+    app.update();
+
+    assert!(spans(&app, text).is_empty());
+    assert_eq!(app.world().get::<Text>(text).unwrap().0, "Ω ok");
+    assert!(app.world().get::<FallbackText>(text).is_none());
+}
+
+#[test]
+fn spans_are_kept_when_the_runs_dont_change() {
+    let mut app = app();
+    spawn_collections(&mut app);
+
+    let text = app
+        .world_mut()
+        .spawn((Text::new("Hi Ωμέγα"), ReactiveFont))
+        .id();
+    app.update();
+    let split = spans(&app, text);
+
+    app.world_mut().entity_mut(text).insert(Bold);
+    app.update();
+    assert_eq!(spans(&app, text), split);
+
+    // Setting the same content again doesn't need new spans either.
+    app.world_mut().get_mut::<Text>(text).unwrap().0 = "Hi Ωμέγα".to_string();
+    app.update();
+    assert_eq!(spans(&app, text), split);
+    assert_eq!(app.world().get::<Text>(text).unwrap().0, "");
+}
+
+#[test]
+fn text_that_doesnt_need_splitting_is_left_alone() {
+    let mut app = app();
+    spawn_collections(&mut app);
+
+    let text = app
+        .world_mut()
+        .spawn((Text::new("Hello"), ReactiveFont))
+        .id();
+    app.update();
+
+    assert!(spans(&app, text).is_empty());
+    assert_eq!(app.world().get::<Text>(text).unwrap().0, "Hello");
+    assert!(app.world().get::<FallbackText>(text).is_none());
+}
+
+#[test]
+fn appending_to_fallback_text_splits_it_again() {
+    let mut app = app();
+    let (_, fallback) = spawn_collections(&mut app);
+
+    let text = app.world_mut().spawn((Text::new("Ω"), ReactiveFont)).id();
+    app.update();
+
+    // Typing out the rest of the line a bit at a time.
+    for next in [" is", " ω"] {
+        app.world_mut()
+            .get_mut::<FallbackText>(text)
+            .unwrap()
+            .push_str(next);
+        app.update();
+    }
+
+    assert_eq!(
+        runs(&spans(&app, text)),
+        [("Ω ", Some(fallback)), ("is ", None), ("ω", Some(fallback))]
+    );
+    assert_eq!(app.world().get::<Text>(text).unwrap().0, "");
+    assert_eq!(app.world().get::<FallbackText>(text).unwrap().0, "Ω is ω");
+}
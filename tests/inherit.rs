@@ -0,0 +1,198 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+fn color_of(app: &App, entity: Entity) -> Color {
+    app.world().get::<TextColor>(entity).unwrap().0
+}
+
+#[test]
+fn containers_style_their_descendants() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let container = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            UsingFont(other),
+            FontColor::new(Color::BLACK),
+            FontSize::Px(25.),
+        ))
+        .id();
+    let middle = app
+        .world_mut()
+        .spawn((Node::default(), ChildOf(container)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, ChildOf(middle)))
+        .id();
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(2).id());
+    assert_eq!(color_of(&app, text), Color::BLACK);
+    assert_eq!(size_of(&app, text), 25.);
+
+    app.world_mut().entity_mut(container).insert((
+        UsingFont(default),
+        FontColor::new(Color::WHITE),
+        FontSize::Px(40.),
+    ));
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(1).id());
+    assert_eq!(color_of(&app, text), Color::WHITE);
+    assert_eq!(size_of(&app, text), 40.);
+}
+
+#[test]
+fn text_closer_to_the_style_wins() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let container = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            FontColor::new(Color::BLACK),
+            FontSize::Px(25.),
+        ))
+        .id();
+    let inner = app
+        .world_mut()
+        .spawn((Node::default(), FontSize::Px(30.), ChildOf(container)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            FontColor::new(Color::WHITE),
+            ChildOf(inner),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(color_of(&app, text), Color::WHITE);
+    assert_eq!(size_of(&app, text), 30.);
+}
+
+#[test]
+fn moving_text_restyles_it() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let first = app
+        .world_mut()
+        .spawn((Node::default(), UsingFont(other), FontSize::Px(25.)))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            FontColor::new(Color::BLACK),
+            FontSize::Px(40.),
+        ))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, ChildOf(first)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+    assert_eq!(size_of(&app, text), 25.);
+
+    app.world_mut().entity_mut(text).insert(ChildOf(second));
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(1).id());
+    assert_eq!(color_of(&app, text), Color::BLACK);
+    assert_eq!(size_of(&app, text), 40.);
+
+    // Taking it out of the hierarchy goes back to the defaults.
+    app.world_mut().entity_mut(text).remove::<ChildOf>();
+    app.update();
+
+    assert_eq!(color_of(&app, text), Color::WHITE);
+    assert_eq!(size_of(&app, text), DefaultFontSize::default().0);
+}
+
+#[test]
+fn moving_a_container_restyles_the_text_in_it() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let other = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let styled = app
+        .world_mut()
+        .spawn((Node::default(), UsingFont(other)))
+        .id();
+    let container = app.world_mut().spawn(Node::default()).id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, ChildOf(container)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(1).id());
+
+    app.world_mut()
+        .entity_mut(container)
+        .insert(ChildOf(styled));
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(2).id());
+}
+
+#[test]
+fn bold_spans_only_bold_themselves() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    let collection = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(font(2)), BoldFont(font(3))))
+        .id();
+
+    let text = app
+        .world_mut()
+        .spawn((
+            Text::new("A "),
+            ReactiveFont,
+            UsingFont(collection),
+            FontSize::Px(30.),
+        ))
+        .id();
+    let bold = app
+        .world_mut()
+        .spawn((TextSpan::new("bold"), ReactiveFont, Bold, ChildOf(text)))
+        .id();
+    let plain = app
+        .world_mut()
+        .spawn((TextSpan::new(" word"), ReactiveFont, ChildOf(text)))
+        .id();
+    app.update();
+
+    assert_eq!(font_of(&app, text), font(2).id());
+    assert_eq!(font_of(&app, bold), font(3).id());
+    assert_eq!(font_of(&app, plain), font(2).id());
+    for entity in [text, bold, plain] {
+        assert_eq!(size_of(&app, entity), 30.);
+    }
+
+    app.world_mut().entity_mut(bold).remove::<Bold>();
+    app.update();
+    assert_eq!(font_of(&app, bold), font(2).id());
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use bevy::{prelude::*, text};
+use bevy_reactive_font::prelude::*;
+use common::{app, spawn_collection};
+
+fn line_height_of(app: &App, entity: Entity) -> text::LineHeight {
+    app.world().get::<TextFont>(entity).unwrap().line_height
+}
+
+fn smoothing_of(app: &App, entity: Entity) -> text::FontSmoothing {
+    app.world().get::<TextFont>(entity).unwrap().font_smoothing
+}
+
+#[test]
+fn defaults_follow_the_collection() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let pixel = spawn_collection(&mut app, 2);
+    app.world_mut().entity_mut(pixel).insert((
+        DefaultLineHeight(LineHeight::Px(8.)),
+        DefaultFontSmoothing(FontSmoothing::None),
+    ));
+    app.insert_resource(DefaultFont(default));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+    assert_eq!(line_height_of(&app, text), LineHeight::default().into());
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::AntiAliased);
+
+    app.world_mut().entity_mut(default).insert((
+        DefaultLineHeight(LineHeight::RelativeToFont(1.5)),
+        DefaultFontSmoothing(FontSmoothing::None),
+    ));
+    app.update();
+    assert_eq!(
+        line_height_of(&app, text),
+        text::LineHeight::RelativeToFont(1.5)
+    );
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::None);
+
+    // Switching collection uses its defaults.
+    app.world_mut().entity_mut(text).insert(UsingFont(pixel));
+    app.update();
+    assert_eq!(line_height_of(&app, text), text::LineHeight::Px(8.));
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::None);
+}
+
+#[test]
+fn text_overrides_the_defaults() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+
+    let container = app
+        .world_mut()
+        .spawn((Node::default(), LineHeight::Px(30.)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            FontSmoothing::None,
+            ChildOf(container),
+        ))
+        .id();
+    app.update();
+    assert_eq!(line_height_of(&app, text), text::LineHeight::Px(30.));
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::None);
+
+    // Changing the defaults doesn't touch them.
+    app.world_mut().entity_mut(default).insert((
+        DefaultLineHeight(LineHeight::Px(10.)),
+        DefaultFontSmoothing(FontSmoothing::AntiAliased),
+    ));
+    app.update();
+    assert_eq!(line_height_of(&app, text), text::LineHeight::Px(30.));
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::None);
+
+    app.world_mut()
+        .entity_mut(container)
+        .insert(LineHeight::Px(40.));
+    app.update();
+    assert_eq!(line_height_of(&app, text), text::LineHeight::Px(40.));
+
+    // Removing them goes back to the defaults.
+    app.world_mut().entity_mut(container).remove::<LineHeight>();
+    app.world_mut().entity_mut(text).remove::<FontSmoothing>();
+    app.update();
+    assert_eq!(line_height_of(&app, text), text::LineHeight::Px(10.));
+    assert_eq!(smoothing_of(&app, text), text::FontSmoothing::AntiAliased);
+}
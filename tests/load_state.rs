@@ -0,0 +1,83 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+
+#[derive(Resource, Default)]
+struct Gated(u32);
+
+fn app() -> App {
+    let mut app = common::app();
+    app.init_asset::<Font>()
+        .init_resource::<Gated>()
+        .add_systems(
+            Update,
+            (|mut gated: ResMut<Gated>| gated.0 += 1).run_if(all_font_collections_loaded),
+        );
+    app
+}
+
+#[test]
+fn nothing_is_loaded_without_collections() {
+    let mut app = app();
+    app.update();
+
+    assert_eq!(app.world().resource::<Gated>().0, 0);
+}
+
+#[test]
+fn gate_opens_once_collections_load() {
+    let mut app = app();
+    let font = common::add_font(
+        &mut app,
+        include_bytes!("../assets/fonts/liberation_mono/regular.ttf"),
+    );
+    let collection = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(font)))
+        .id();
+    app.update();
+    app.update();
+
+    assert!(
+        app.world()
+            .get::<FontCollectionLoadState>(collection)
+            .unwrap()
+            .is_loaded()
+    );
+    assert!(app.world().resource::<Gated>().0 > 0);
+}
+
+#[test]
+fn failed_collections_keep_the_gate_closed() {
+    let mut app = app();
+    let missing = app
+        .world()
+        .resource::<AssetServer>()
+        .load::<Font>("fonts/missing.ttf");
+    let collection = app
+        .world_mut()
+        .spawn((FontCollection, RegularFont(missing)))
+        .id();
+
+    for _ in 0..200 {
+        app.update();
+        if app
+            .world()
+            .get::<FontCollectionLoadState>(collection)
+            .unwrap()
+            .is_failed()
+        {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert!(
+        app.world()
+            .get::<FontCollectionLoadState>(collection)
+            .unwrap()
+            .is_failed()
+    );
+    assert_eq!(app.world().resource::<Gated>().0, 0);
+}
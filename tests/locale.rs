@@ -0,0 +1,78 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+fn default_font(app: &App) -> Option<Entity> {
+    app.world().get_resource::<DefaultFont>().map(|font| font.0)
+}
+
+#[test]
+fn switching_locale_updates_the_text() {
+    let mut app = app();
+    let english = spawn_collection(&mut app, 1);
+    let japanese = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(font(2)),
+            FontName::new("japanese"),
+        ))
+        .id();
+    app.insert_resource(
+        FontLocale::new("en")
+            .with_font("en", english)
+            .with_font("ja", "japanese"),
+    );
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    let own_font = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, UsingFont(english)))
+        .id();
+    app.update();
+    assert_eq!(default_font(&app), Some(english));
+    assert_eq!(font_of(&app, text), font(1).id());
+
+    app.world_mut()
+        .resource_mut::<FontLocale>()
+        .set_active("ja");
+    app.update();
+
+    assert_eq!(default_font(&app), Some(japanese));
+    assert_eq!(font_of(&app, text), font(2).id());
+    assert_eq!(font_of(&app, own_font), font(1).id());
+
+    app.world_mut()
+        .resource_mut::<FontLocale>()
+        .set_active("en");
+    app.update();
+
+    assert_eq!(default_font(&app), Some(english));
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn collections_spawned_after_the_locale_are_used() {
+    let mut app = app();
+    let english = spawn_collection(&mut app, 1);
+    let japanese = app.world_mut().spawn_empty().id();
+    app.insert_resource(DefaultFont(english));
+    app.insert_resource(FontLocale::new("ja").with_font("ja", japanese));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    // Settle the locale and registry changes, so only spawning the collection can update it.
+    app.update();
+    app.update();
+    assert_eq!(default_font(&app), Some(english));
+    assert_eq!(font_of(&app, text), font(1).id());
+
+    app.world_mut()
+        .entity_mut(japanese)
+        .insert((FontCollection, RegularFont(font(2))));
+    app.update();
+
+    assert_eq!(default_font(&app), Some(japanese));
+    assert_eq!(font_of(&app, text), font(2).id());
+}
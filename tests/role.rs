@@ -0,0 +1,107 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+/// A collection with a bold face and a default size of 20, used as the [`DefaultFont`].
+fn spawn_collection(app: &mut App) -> Entity {
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(font(1)),
+            BoldFont(font(2)),
+            DefaultFontSize(20.),
+        ))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+    collection
+}
+
+#[test]
+fn roles_are_styled_by_the_type_scale() {
+    let mut app = app();
+    spawn_collection(&mut app);
+
+    let heading = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, TextRole::Heading2))
+        .id();
+    let caption = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, TextRole::Caption))
+        .id();
+    app.update();
+
+    // The default type scale makes second level headings 1.5rem and bold.
+    assert_eq!(size_of(&app, heading), 30.);
+    assert_eq!(font_of(&app, heading), font(2).id());
+    assert_eq!(size_of(&app, caption), 16.);
+    assert_eq!(font_of(&app, caption), font(1).id());
+}
+
+#[test]
+fn text_overrides_its_role() {
+    let mut app = app();
+    spawn_collection(&mut app);
+
+    let heading = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            TextRole::Heading2,
+            FontSize::Px(12.),
+            FontWeight::NORMAL,
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(size_of(&app, heading), 12.);
+    assert_eq!(font_of(&app, heading), font(1).id());
+}
+
+#[test]
+fn editing_the_type_scale_restyles_text() {
+    let mut app = app();
+    let collection = spawn_collection(&mut app);
+
+    let heading = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, TextRole::Heading2))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .get_mut::<TypeScale>(collection)
+        .unwrap()
+        .insert(
+            TextRole::Heading2,
+            RoleStyle::new()
+                .with_size(FontSize::Rem(2.))
+                .with_weight(FontWeight::NORMAL)
+                .with_color(Color::BLACK),
+        );
+    app.update();
+
+    assert_eq!(size_of(&app, heading), 40.);
+    assert_eq!(font_of(&app, heading), font(1).id());
+    assert_eq!(
+        app.world().get::<TextColor>(heading).unwrap().0,
+        Color::BLACK
+    );
+
+    // Changing the role picks up its style.
+    app.world_mut()
+        .entity_mut(heading)
+        .insert(TextRole::Heading1);
+    app.update();
+
+    assert_eq!(size_of(&app, heading), 40.);
+    assert_eq!(font_of(&app, heading), font(2).id());
+}
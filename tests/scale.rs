@@ -0,0 +1,89 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, spawn_collection};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+fn spawn_text(app: &mut App, bundle: impl Bundle) -> Entity {
+    app.world_mut()
+        .spawn((Text::default(), ReactiveFont, FontSize::Px(10.), bundle))
+        .id()
+}
+
+#[test]
+fn font_scale_changes_every_size() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FontScale(2.));
+
+    let text = spawn_text(&mut app, ());
+    app.update();
+    assert_eq!(size_of(&app, text), 20.);
+
+    app.insert_resource(FontScale(1.5));
+    app.update();
+    assert_eq!(size_of(&app, text), 15.);
+}
+
+#[test]
+fn collection_scale_overrides_font_scale() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let scaled = spawn_collection(&mut app, 2);
+    app.world_mut()
+        .entity_mut(scaled)
+        .insert(CollectionFontScale(3.));
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FontScale(2.));
+
+    let text = spawn_text(&mut app, ());
+    let scaled_text = spawn_text(&mut app, UsingFont(scaled));
+    app.update();
+    assert_eq!(size_of(&app, text), 20.);
+    assert_eq!(size_of(&app, scaled_text), 30.);
+
+    // Changing the global scale doesn't affect it.
+    app.insert_resource(FontScale(1.));
+    app.update();
+    assert_eq!(size_of(&app, text), 10.);
+    assert_eq!(size_of(&app, scaled_text), 30.);
+
+    app.world_mut()
+        .entity_mut(scaled)
+        .insert(CollectionFontScale(0.5));
+    app.update();
+    assert_eq!(size_of(&app, scaled_text), 5.);
+
+    app.world_mut()
+        .entity_mut(scaled)
+        .remove::<CollectionFontScale>();
+    app.update();
+    assert_eq!(size_of(&app, scaled_text), 10.);
+}
+
+#[test]
+fn fixed_sizes_are_not_scaled() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    app.world_mut()
+        .entity_mut(default)
+        .insert(CollectionFontScale(3.));
+    app.insert_resource(DefaultFont(default));
+    app.insert_resource(FontScale(2.));
+
+    let fixed = spawn_text(&mut app, FixedFontSize);
+    let container = app.world_mut().spawn((Node::default(), FixedFontSize)).id();
+    let inside = spawn_text(&mut app, ChildOf(container));
+    app.update();
+    assert_eq!(size_of(&app, fixed), 10.);
+    assert_eq!(size_of(&app, inside), 10.);
+
+    app.world_mut().entity_mut(fixed).remove::<FixedFontSize>();
+    app.update();
+    assert_eq!(size_of(&app, fixed), 30.);
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use bevy::{
+    prelude::*,
+    reflect::{DynamicStruct, FromReflect},
+};
+use bevy_reactive_font::prelude::*;
+use common::{app, font, font_of, spawn_collection};
+
+/// The camera UI is rendered to is normally worked out by bevy's UI systems, which aren't added
+/// here. They give it to every node, so it has to be inserted on each one.
+fn target_camera(camera: Entity) -> ComputedUiTargetCamera {
+    let mut target = DynamicStruct::default();
+    target.insert("camera", camera);
+    ComputedUiTargetCamera::from_reflect(&target).unwrap()
+}
+
+#[test]
+fn camera_scope_is_used_for_text_rendered_to_it() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let scoped = spawn_collection(&mut app, 2);
+    app.insert_resource(DefaultFont(default));
+
+    let camera = app
+        .world_mut()
+        .spawn((Camera::default(), DefaultFontScope(scoped)))
+        .id();
+    let rendered = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, target_camera(camera)))
+        .id();
+    let elsewhere = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+
+    assert_eq!(font_of(&app, rendered), font(2).id());
+    assert_eq!(font_of(&app, elsewhere), font(1).id());
+}
+
+#[test]
+fn collections_are_looked_up_in_order() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let camera_scoped = spawn_collection(&mut app, 2);
+    let root_scoped = spawn_collection(&mut app, 3);
+    let parent_font = spawn_collection(&mut app, 4);
+    let own_font = spawn_collection(&mut app, 5);
+    app.insert_resource(DefaultFont(default));
+
+    let camera = app
+        .world_mut()
+        .spawn((Camera::default(), DefaultFontScope(camera_scoped)))
+        .id();
+    let root = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            DefaultFontScope(root_scoped),
+            target_camera(camera),
+        ))
+        .id();
+    let parent = app
+        .world_mut()
+        .spawn((
+            Node::default(),
+            UsingFont(parent_font),
+            target_camera(camera),
+            ChildOf(root),
+        ))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            UsingFont(own_font),
+            target_camera(camera),
+            ChildOf(parent),
+        ))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(5).id());
+
+    app.world_mut().entity_mut(text).remove::<UsingFont>();
+    app.update();
+    assert_eq!(font_of(&app, text), font(4).id());
+
+    app.world_mut().entity_mut(parent).remove::<UsingFont>();
+    app.update();
+    assert_eq!(font_of(&app, text), font(3).id());
+
+    app.world_mut()
+        .entity_mut(root)
+        .remove::<DefaultFontScope>();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    app.world_mut()
+        .entity_mut(camera)
+        .remove::<DefaultFontScope>();
+    app.update();
+    assert_eq!(font_of(&app, text), font(1).id());
+}
+
+#[test]
+fn text_moved_to_another_camera_uses_its_scope() {
+    let mut app = app();
+    let default = spawn_collection(&mut app, 1);
+    let first_scoped = spawn_collection(&mut app, 2);
+    let second_scoped = spawn_collection(&mut app, 3);
+    app.insert_resource(DefaultFont(default));
+
+    let first = app
+        .world_mut()
+        .spawn((Camera::default(), DefaultFontScope(first_scoped)))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn((Camera::default(), DefaultFontScope(second_scoped)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, target_camera(first)))
+        .id();
+    app.update();
+    assert_eq!(font_of(&app, text), font(2).id());
+
+    app.world_mut()
+        .entity_mut(text)
+        .insert(target_camera(second));
+    app.update();
+    assert_eq!(font_of(&app, text), font(3).id());
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, spawn_collection};
+
+fn shadow_of(app: &App, entity: Entity) -> Option<Vec2> {
+    app.world()
+        .get::<TextShadow>(entity)
+        .map(|shadow| shadow.offset)
+}
+
+#[test]
+fn default_shadow_is_added_and_removed() {
+    let mut app = app();
+    let collection = spawn_collection(&mut app, 1);
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultTextShadow::new(Vec2::splat(2.), Color::BLACK));
+    app.insert_resource(DefaultFont(collection));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+    assert_eq!(shadow_of(&app, text), Some(Vec2::splat(2.)));
+
+    app.world_mut()
+        .entity_mut(collection)
+        .remove::<DefaultTextShadow>();
+    app.update();
+    assert_eq!(shadow_of(&app, text), None);
+}
+
+#[test]
+fn shadows_added_by_hand_are_kept() {
+    let mut app = app();
+    let collection = spawn_collection(&mut app, 1);
+    app.insert_resource(DefaultFont(collection));
+
+    let shadow = TextShadow {
+        offset: Vec2::splat(4.),
+        ..Default::default()
+    };
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, shadow))
+        .id();
+    app.update();
+    assert_eq!(shadow_of(&app, text), Some(Vec2::splat(4.)));
+
+    // Still kept when the text is resolved again.
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(30.));
+    app.update();
+    assert_eq!(shadow_of(&app, text), Some(Vec2::splat(4.)));
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, spawn_collection};
+
+fn size_of(app: &App, entity: Entity) -> f32 {
+    app.world().get::<TextFont>(entity).unwrap().font_size
+}
+
+/// A collection with a default size of 20, used as the [`DefaultFont`].
+fn spawn_default_collection(app: &mut App) -> Entity {
+    let collection = spawn_collection(app, 1);
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(20.));
+    app.insert_resource(DefaultFont(collection));
+    collection
+}
+
+#[test]
+fn rem_follows_the_default_size() {
+    let mut app = app();
+    let collection = spawn_default_collection(&mut app);
+
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontSize::Rem(1.5)))
+        .id();
+    let px = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontSize::Px(12.)))
+        .id();
+    app.update();
+    assert_eq!(size_of(&app, text), 30.);
+    assert_eq!(size_of(&app, px), 12.);
+
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(10.));
+    app.update();
+
+    assert_eq!(size_of(&app, text), 15.);
+    assert_eq!(size_of(&app, px), 12.);
+}
+
+#[test]
+fn em_and_percent_are_relative_to_the_container() {
+    let mut app = app();
+    spawn_default_collection(&mut app);
+
+    let container = app
+        .world_mut()
+        .spawn((Node::default(), FontSize::Px(30.)))
+        .id();
+    let em = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            FontSize::Em(2.),
+            ChildOf(container),
+        ))
+        .id();
+    let percent = app
+        .world_mut()
+        .spawn((
+            Text::default(),
+            ReactiveFont,
+            FontSize::Percent(50.),
+            ChildOf(container),
+        ))
+        .id();
+    app.update();
+    assert_eq!(size_of(&app, em), 60.);
+    assert_eq!(size_of(&app, percent), 15.);
+
+    app.world_mut()
+        .entity_mut(container)
+        .insert(FontSize::Px(10.));
+    app.update();
+
+    assert_eq!(size_of(&app, em), 20.);
+    assert_eq!(size_of(&app, percent), 5.);
+}
+
+#[test]
+fn nested_relative_sizes_multiply() {
+    let mut app = app();
+    let collection = spawn_default_collection(&mut app);
+
+    // Without a size above it, em is relative to the default size.
+    let outer = app
+        .world_mut()
+        .spawn((Node::default(), FontSize::Em(2.)))
+        .id();
+    let inner = app
+        .world_mut()
+        .spawn((Node::default(), FontSize::Percent(150.), ChildOf(outer)))
+        .id();
+    let text = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, ChildOf(inner)))
+        .id();
+    app.update();
+    assert_eq!(size_of(&app, text), 60.);
+
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(10.));
+    app.update();
+    assert_eq!(size_of(&app, text), 30.);
+}
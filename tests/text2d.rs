@@ -0,0 +1,190 @@
+mod common;
+
+use bevy::{
+    prelude::*,
+    sprite::Text2dShadow,
+    window::{PrimaryWindow, WindowResized, WindowResolution},
+};
+use bevy_reactive_font::prelude::*;
+use common::{app, font};
+
+fn spawn_default_collection(app: &mut App) -> Entity {
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(font(1)),
+            BoldFont(font(2)),
+            DefaultFontSize(20.),
+            DefaultFontColor::new(Color::WHITE),
+        ))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+    collection
+}
+
+fn text_font(app: &App, entity: Entity) -> &TextFont {
+    app.world().get::<TextFont>(entity).unwrap()
+}
+
+#[test]
+fn world_labels_are_styled() {
+    let mut app = app();
+    spawn_default_collection(&mut app);
+
+    let label = app
+        .world_mut()
+        .spawn((Text2d::new("Shop"), ReactiveFont, Bold))
+        .id();
+    app.update();
+
+    assert_eq!(text_font(&app, label).font.id(), font(2).id());
+    assert_eq!(text_font(&app, label).font_size, 20.);
+    assert_eq!(app.world().get::<TextColor>(label).unwrap().0, Color::WHITE);
+}
+
+#[test]
+fn nameplate_spans_inherit_from_their_text() {
+    let mut app = app();
+    spawn_default_collection(&mut app);
+    app.world_mut().spawn((
+        FontCollection,
+        FontName::new("nameplate"),
+        RegularFont(font(3)),
+        BoldFont(font(4)),
+        DefaultFontSize(12.),
+    ));
+
+    let nameplate = app
+        .world_mut()
+        .spawn((
+            Text2d::new("Goblin "),
+            ReactiveFont,
+            UseFontNamed::new("nameplate"),
+            FontColor::new(Color::BLACK),
+        ))
+        .id();
+    let level = app
+        .world_mut()
+        .spawn((
+            TextSpan::new("Lv. 3"),
+            ReactiveFont,
+            Bold,
+            ChildOf(nameplate),
+        ))
+        .id();
+    app.update();
+    app.update();
+
+    assert_eq!(text_font(&app, nameplate).font.id(), font(3).id());
+    assert_eq!(text_font(&app, level).font.id(), font(4).id());
+    assert_eq!(text_font(&app, level).font_size, 12.);
+    assert_eq!(app.world().get::<TextColor>(level).unwrap().0, Color::BLACK);
+}
+
+#[test]
+fn damage_numbers_follow_the_default_size() {
+    let mut app = app();
+    let collection = spawn_default_collection(&mut app);
+
+    let numbers = (0..10)
+        .map(|damage| {
+            app.world_mut()
+                .spawn((
+                    Text2d::new(damage.to_string()),
+                    ReactiveFont,
+                    FontSize::Rem(1.5),
+                ))
+                .id()
+        })
+        .collect::<Vec<_>>();
+    app.update();
+
+    assert!(
+        numbers
+            .iter()
+            .all(|number| text_font(&app, *number).font_size == 30.)
+    );
+
+    // Damage numbers come and go, despawning some shouldn't stop the rest from updating.
+    numbers[..5].iter().for_each(|number| {
+        app.world_mut().despawn(*number);
+    });
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(10.));
+    app.update();
+
+    assert!(
+        numbers[5..]
+            .iter()
+            .all(|number| text_font(&app, *number).font_size == 15.)
+    );
+}
+
+#[test]
+fn world_labels_get_a_text2d_shadow() {
+    let mut app = app();
+    let collection = spawn_default_collection(&mut app);
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultTextShadow::new(Vec2::new(2., 3.), Color::BLACK));
+
+    let label = app
+        .world_mut()
+        .spawn((
+            Text2d::new("Hp"),
+            ReactiveFont,
+            children![(TextSpan::new(" 10"), ReactiveFont)],
+        ))
+        .id();
+    app.update();
+
+    let shadow = app.world().get::<Text2dShadow>(label).unwrap();
+    assert_eq!(shadow.offset, Vec2::new(2., -3.));
+    assert_eq!(shadow.color, Color::BLACK);
+    assert!(app.world().get::<TextShadow>(label).is_none());
+
+    app.world_mut().entity_mut(label).insert(NoTextShadow);
+    app.update();
+
+    assert!(app.world().get::<Text2dShadow>(label).is_none());
+}
+
+#[test]
+fn viewport_sizes_follow_the_primary_window() {
+    let mut app = app();
+    spawn_default_collection(&mut app);
+
+    let window = app
+        .world_mut()
+        .spawn((
+            Window {
+                resolution: WindowResolution::new(800, 600),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ))
+        .id();
+    let label = app
+        .world_mut()
+        .spawn((Text2d::new("Wave 1"), ReactiveFont, FontSize::Vh(10.)))
+        .id();
+    app.update();
+
+    assert_eq!(text_font(&app, label).font_size, 60.);
+
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(800., 400.);
+    app.world_mut().write_message(WindowResized {
+        window,
+        width: 800.,
+        height: 400.,
+    });
+    app.update();
+
+    assert_eq!(text_font(&app, label).font_size, 40.);
+}
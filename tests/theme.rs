@@ -0,0 +1,66 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font};
+
+fn color_of(app: &App, entity: Entity) -> Color {
+    app.world().get::<TextColor>(entity).unwrap().0
+}
+
+#[test]
+fn default_colors_follow_the_theme() {
+    let mut app = app();
+    app.insert_resource(
+        FontTheme::new("light")
+            .with_theme(
+                "light",
+                ThemePalette::new().with_color("text", Color::BLACK),
+            )
+            .with_theme("dark", ThemePalette::new().with_color("text", Color::WHITE)),
+    );
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(font(1)),
+            DefaultFontColor::theme("text"),
+        ))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    let own_color = app
+        .world_mut()
+        .spawn((Text::default(), ReactiveFont, FontColor::new(Color::BLACK)))
+        .id();
+    app.update();
+    assert_eq!(color_of(&app, text), Color::BLACK);
+
+    app.world_mut()
+        .resource_mut::<FontTheme>()
+        .set_active("dark");
+    app.update();
+
+    assert_eq!(color_of(&app, text), Color::WHITE);
+    assert_eq!(color_of(&app, own_color), Color::BLACK);
+}
+
+#[test]
+fn missing_theme_colors_use_the_default_color() {
+    let mut app = app();
+    let collection = app
+        .world_mut()
+        .spawn((
+            FontCollection,
+            RegularFont(font(1)),
+            DefaultFontColor::theme("text"),
+        ))
+        .id();
+    app.insert_resource(DefaultFont(collection));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+
+    assert_eq!(color_of(&app, text), Color::default());
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_reactive_font::prelude::*;
+use common::{app, font, spawn_collection};
+
+#[test]
+fn update_events_resolve_their_part() {
+    let mut app = app();
+    let collection = spawn_collection(&mut app, 1);
+    app.world_mut()
+        .entity_mut(collection)
+        .insert(DefaultFontSize(20.));
+    app.insert_resource(DefaultFont(collection));
+
+    let text = app.world_mut().spawn((Text::default(), ReactiveFont)).id();
+    app.update();
+
+    // Change the text behind the plugin's back.
+    let mut entity = app.world_mut().entity_mut(text);
+    *entity.get_mut::<TextFont>().unwrap() = TextFont {
+        font: font(2),
+        font_size: 50.,
+        ..Default::default()
+    };
+    entity.insert(TextColor(Color::BLACK));
+
+    entity.trigger(UpdateFontSize);
+    app.update();
+    let text_font = app.world().get::<TextFont>(text).unwrap();
+    assert_eq!(text_font.font_size, 20.);
+    assert_eq!(text_font.font.id(), font(2).id());
+
+    app.world_mut().entity_mut(text).trigger(UpdateFont);
+    app.update();
+    assert_eq!(
+        app.world().get::<TextFont>(text).unwrap().font.id(),
+        font(1).id()
+    );
+    assert_eq!(app.world().get::<TextColor>(text).unwrap().0, Color::BLACK);
+
+    app.world_mut().entity_mut(text).trigger(UpdateFontColor);
+    app.update();
+    assert_eq!(app.world().get::<TextColor>(text).unwrap().0, Color::WHITE);
+}